@@ -0,0 +1,4 @@
+// Utilities shared by every year
+
+pub mod interval;
+pub mod rational;
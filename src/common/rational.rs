@@ -0,0 +1,142 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// An exact fraction kept in lowest terms with a positive denominator. Every
+/// operation panics on i128 overflow rather than silently wrapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+pub fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl Rational {
+    pub fn new(num: i128, den: i128) -> Rational {
+        assert!(den != 0, "Zero denominator");
+        let sign = den.signum();
+        let divisor = gcd(num, den).max(1);
+
+        Rational {
+            num: sign * num / divisor,
+            den: sign * den / divisor,
+        }
+    }
+
+    pub fn integer(value: i128) -> Rational {
+        Rational { num: value, den: 1 }
+    }
+
+    pub fn to_integer(self) -> Option<i128> {
+        (self.den == 1).then_some(self.num)
+    }
+}
+
+fn checked(value: Option<i128>) -> i128 {
+    value.expect("Rational overflow")
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Rational {
+        let divisor = gcd(self.den, other.den);
+        let left = checked(self.num.checked_mul(other.den / divisor));
+        let right = checked(other.num.checked_mul(self.den / divisor));
+        let den = checked((self.den / divisor).checked_mul(other.den));
+
+        Rational::new(checked(left.checked_add(right)), den)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Rational {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Rational {
+        // Cross-reduce first so the products stay as small as possible.
+        let a = gcd(self.num, other.den).max(1);
+        let b = gcd(other.num, self.den).max(1);
+
+        Rational::new(
+            checked((self.num / a).checked_mul(other.num / b)),
+            checked((self.den / b).checked_mul(other.den / a)),
+        )
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (*self - *other).num.cmp(&0)
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Solves `matrix * x = rhs` by fraction-free (Bareiss) Gauss-Jordan
+/// elimination. Every intermediate value is a minor of the augmented matrix,
+/// so the work stays in i128 whenever those minors do. Returns `None` when
+/// the system has no unique solution or a minor overflows.
+pub fn solve_linear(matrix: &[Vec<i128>], rhs: &[i128]) -> Option<Vec<Rational>> {
+    let size = rhs.len();
+    let mut rows: Vec<Vec<i128>> = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, value)| row.iter().copied().chain([*value]).collect())
+        .collect();
+    let mut previous = 1;
+
+    for col in 0..size {
+        let pivot = (col..size).find(|row| rows[*row][col] != 0)?;
+        rows.swap(col, pivot);
+        let pivot_row = rows[col].clone();
+
+        for (index, row) in rows.iter_mut().enumerate() {
+            if index == col {
+                continue;
+            }
+            let factor = row[col];
+            for (value, pivot_value) in row.iter_mut().zip(pivot_row.iter()) {
+                let scaled = pivot_row[col].checked_mul(*value)?;
+                let removed = factor.checked_mul(*pivot_value)?;
+                *value = scaled.checked_sub(removed)? / previous;
+            }
+        }
+        previous = pivot_row[col];
+    }
+
+    // Each row now reads det * x_i = det_i.
+    Some(
+        rows.iter()
+            .map(|row| Rational::new(row[size], previous))
+            .collect(),
+    )
+}
@@ -1,51 +1,75 @@
+use std::collections::HashMap;
 use std::env;
 
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day2;
-mod day20;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
+mod common;
+mod y2023;
+
+const DEFAULT_YEAR: u32 = 2023;
+
+fn registry() -> HashMap<(u32, u32), fn()> {
+    let years = [(2023, y2023::days())];
+
+    years
+        .into_iter()
+        .flat_map(|(year, days)| days.into_iter().map(move |(day, run)| ((year, day), run)))
+        .collect()
+}
+
+pub type Mode = fn(&[String]);
+
+/// Extra per-day commands, run as `DAY MODE [ARGS...]`.
+fn mode_registry() -> HashMap<(u32, u32, &'static str), Mode> {
+    let years = [(2023, y2023::modes())];
+
+    years
+        .into_iter()
+        .flat_map(|(year, modes)| {
+            modes
+                .into_iter()
+                .map(move |(day, name, mode)| ((year, day, name), mode))
+        })
+        .collect()
+}
+
+fn parse_args(args: &[String]) -> (u32, String, Vec<String>) {
+    let mut year = DEFAULT_YEAR;
+    let mut positional = Vec::new();
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        if arg == "--year" {
+            let value = args.next().expect("--year needs a value");
+            year = value.parse().expect("Invalid year");
+        } else if let Some(value) = arg.strip_prefix("--year=") {
+            year = value.parse().expect("Invalid year");
+        } else {
+            positional.push(arg.to_string());
+        }
+    }
+
+    if positional.is_empty() {
+        panic!("Usage: advent-of-code [--year YEAR] DAY [MODE ARGS...]");
+    }
+    let day = positional.remove(0);
+    (year, day, positional)
+}
 
 fn main() {
     let args: Vec<String> = env::args().collect();
-    let day = &args[1];
-
-    match day as &str {
-        "1" => day1::run(),
-        "2" => day2::run(),
-        "3" => day3::run(),
-        "4" => day4::run(),
-        "5" => day5::run(),
-        "6" => day6::run(),
-        "7" => day7::run(),
-        "8" => day8::run(),
-        "9" => day9::run(),
-        "10" => day10::run(),
-        "11" => day11::run(),
-        "12" => day12::run(),
-        "13" => day13::run(),
-        "14" => day14::run(),
-        "15" => day15::run(),
-        "16" => day16::run(),
-        "17" => day17::run(),
-        "18" => day18::run(),
-        "19" => day19::run(),
-        "20" => day20::run(),
-        _ => println!("Day {day} not implemented!"),
+    let (year, day, rest) = parse_args(&args[1..]);
+    let day_num: Option<u32> = day.parse().ok();
+
+    if let Some(mode) = rest.first() {
+        let found = day_num.and_then(|d| mode_registry().get(&(year, d, mode.as_str())).copied());
+        match found {
+            Some(run_mode) => run_mode(&rest[1..]),
+            None => println!("Mode {mode} for day {day} of {year} not implemented!"),
+        }
+        return;
+    }
+
+    match day_num.and_then(|d| registry().get(&(year, d)).copied()) {
+        Some(run) => run(),
+        None => println!("Day {day} of {year} not implemented!"),
     }
 }
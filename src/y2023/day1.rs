@@ -0,0 +1,403 @@
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::fs;
+
+fn read_input() -> String {
+    fs::read_to_string("./inputs/2023/day1.txt").expect("No file found")
+}
+
+fn sum_contents(calibration_fn: &dyn Fn(&str) -> Option<u32>) -> u32 {
+    let contents = read_input();
+    let lines = contents.split("\n");
+
+    return lines
+        .filter(|line| line.len() > 0)
+        .filter_map(calibration_fn)
+        .sum();
+}
+
+fn calibration_value1(input: &str) -> Option<u32> {
+    let mut first: Option<char> = None;
+    let mut last: Option<char> = None;
+
+    for c in input.chars() {
+        if c.is_digit(10) {
+            if first.is_none() {
+                first = Some(c);
+            }
+            last = Some(c);
+        }
+    }
+
+    let mut result = first?.to_string();
+    result.push(last?);
+
+    return result.parse().ok();
+}
+
+fn part1() -> u32 {
+    return sum_contents(&calibration_value1);
+}
+
+const ENGLISH: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+const GERMAN: [(&str, u32); 9] = [
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fünf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+];
+
+const FRENCH: [(&str, u32); 9] = [
+    ("un", 1),
+    ("deux", 2),
+    ("trois", 3),
+    ("quatre", 4),
+    ("cinq", 5),
+    ("six", 6),
+    ("sept", 7),
+    ("huit", 8),
+    ("neuf", 9),
+];
+
+/// The spelled-out numbers recognised alongside plain digits. Values may have
+/// more than one digit, e.g. "eighteen" for 18.
+#[derive(Debug)]
+struct Lexicon {
+    matcher: DigitMatcher,
+}
+
+impl Lexicon {
+    fn new(words: Vec<(String, u32)>) -> Lexicon {
+        Lexicon {
+            matcher: DigitMatcher::new(&words),
+        }
+    }
+
+    fn builtin(table: &[(&str, u32)]) -> Lexicon {
+        Lexicon::new(table.iter().map(|(w, v)| (w.to_string(), *v)).collect())
+    }
+
+    /// Parses one `word value` pair per line, skipping blank lines and `#`
+    /// comments.
+    fn parse(input: &str) -> Lexicon {
+        let words = input
+            .lines()
+            .map(|l| l.trim())
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .map(|l| {
+                let (word, value) = l
+                    .rsplit_once(char::is_whitespace)
+                    .expect("Invalid lexicon line");
+                (
+                    word.trim().to_string(),
+                    value.parse().expect("Invalid lexicon value"),
+                )
+            })
+            .collect();
+
+        Lexicon::new(words)
+    }
+
+    fn from_file(path: &str) -> Lexicon {
+        Lexicon::parse(&fs::read_to_string(path).expect("No file found"))
+    }
+
+    /// One of the built-in tables by language name, otherwise a lexicon file.
+    fn load(name: &str) -> Lexicon {
+        match name {
+            "english" => Lexicon::builtin(&ENGLISH),
+            "german" => Lexicon::builtin(&GERMAN),
+            "french" => Lexicon::builtin(&FRENCH),
+            path => Lexicon::from_file(path),
+        }
+    }
+}
+
+/// A digit or digit word found in a line, with its byte span.
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct DigitMatch {
+    start: usize,
+    end: usize,
+    value: u32,
+}
+
+#[derive(Debug, Default)]
+struct MatcherNode {
+    next: HashMap<u8, usize>,
+    fail: usize,
+    // (pattern length, value) for every pattern ending at this node
+    outputs: Vec<(usize, u32)>,
+}
+
+/// Aho-Corasick automaton over the digits 1 to 9 and a lexicon's words, so
+/// overlapping words like "eightwo" are all found in a single forward pass.
+/// Zero is only matched when the lexicon has it, e.g. as "zero 0", as part 2
+/// has always left it out.
+#[derive(Debug)]
+struct DigitMatcher {
+    nodes: Vec<MatcherNode>,
+}
+
+impl DigitMatcher {
+    fn new(words: &[(String, u32)]) -> DigitMatcher {
+        let mut nodes = vec![MatcherNode::default()];
+        let digits = (1..10).map(|d| (d.to_string(), d));
+
+        for (word, value) in digits.chain(words.iter().cloned()) {
+            let mut current = 0;
+            for byte in word.bytes() {
+                current = match nodes[current].next.get(&byte) {
+                    Some(next) => *next,
+                    None => {
+                        nodes.push(MatcherNode::default());
+                        let next = nodes.len() - 1;
+                        nodes[current].next.insert(byte, next);
+                        next
+                    }
+                };
+            }
+            nodes[current].outputs.push((word.len(), value));
+        }
+
+        // Breadth-first so every fail link points at an already finished node.
+        let mut queue: VecDeque<usize> = nodes[0].next.values().copied().collect();
+        while let Some(current) = queue.pop_front() {
+            let edges: Vec<(u8, usize)> =
+                nodes[current].next.iter().map(|(b, n)| (*b, *n)).collect();
+            for (byte, child) in edges {
+                let mut fallback = nodes[current].fail;
+                while fallback != 0 && !nodes[fallback].next.contains_key(&byte) {
+                    fallback = nodes[fallback].fail;
+                }
+                let fail = match nodes[fallback].next.get(&byte) {
+                    Some(next) if *next != child => *next,
+                    _ => 0,
+                };
+                let inherited = nodes[fail].outputs.clone();
+                nodes[child].fail = fail;
+                nodes[child].outputs.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+
+        DigitMatcher { nodes }
+    }
+
+    fn find_matches(&self, input: &str) -> Vec<DigitMatch> {
+        let mut matches = Vec::new();
+        let mut current = 0;
+
+        for (i, byte) in input.bytes().enumerate() {
+            while current != 0 && !self.nodes[current].next.contains_key(&byte) {
+                current = self.nodes[current].fail;
+            }
+            current = self.nodes[current].next.get(&byte).copied().unwrap_or(0);
+
+            for (len, value) in self.nodes[current].outputs.iter() {
+                matches.push(DigitMatch {
+                    start: i + 1 - len,
+                    end: i + 1,
+                    value: *value,
+                });
+            }
+        }
+
+        matches.sort_by_key(|m| (m.start, m.end));
+        matches
+    }
+}
+
+fn leading_digit(mut value: u32) -> u32 {
+    while value >= 10 {
+        value /= 10;
+    }
+    value
+}
+
+/// The earliest match (longest on ties) and the latest ending match (longest
+/// on ties), so "eighteen" wins over the "eight" inside it.
+fn first_and_last(matches: &[DigitMatch]) -> Option<(&DigitMatch, &DigitMatch)> {
+    let first = matches
+        .iter()
+        .min_by_key(|m| (m.start, usize::MAX - m.end))?;
+    let last = matches
+        .iter()
+        .max_by_key(|m| (m.end, usize::MAX - m.start))?;
+    Some((first, last))
+}
+
+fn calibration_value2(lexicon: &Lexicon, input: &str) -> Option<u32> {
+    let matches = lexicon.matcher.find_matches(input);
+    let (first, last) = first_and_last(&matches)?;
+
+    Some(leading_digit(first.value) * 10 + last.value % 10)
+}
+
+fn part2() -> u32 {
+    let lexicon = Lexicon::builtin(&ENGLISH);
+    sum_contents(&|line| calibration_value2(&lexicon, line))
+}
+
+/// How a single line was read: its first and last matches and the resulting
+/// calibration value, or `None` when the line has no digits at all.
+#[derive(Debug)]
+struct LineExplanation<'a> {
+    line: &'a str,
+    matches: Option<(DigitMatch, DigitMatch)>,
+}
+
+impl LineExplanation<'_> {
+    fn value(&self) -> Option<u32> {
+        self.matches
+            .as_ref()
+            .map(|(first, last)| leading_digit(first.value) * 10 + last.value % 10)
+    }
+
+    fn describe(&self, found: &DigitMatch) -> String {
+        format!(
+            "\"{}\" at {}..{}",
+            &self.line[found.start..found.end],
+            found.start,
+            found.end
+        )
+    }
+}
+
+fn explain_lines<'a>(lexicon: &Lexicon, contents: &'a str) -> Vec<LineExplanation<'a>> {
+    contents
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let matches = lexicon.matcher.find_matches(line);
+            let matches = first_and_last(&matches).map(|(first, last)| (*first, *last));
+
+            LineExplanation { line, matches }
+        })
+        .collect()
+}
+
+/// Prints how every line was read. Takes an optional lexicon: "digits" for
+/// the part 1 rules, a built-in language name, or a lexicon file path.
+pub fn explain(args: &[String]) {
+    let lexicon = match args.first().map(|a| a.as_str()) {
+        None => Lexicon::builtin(&ENGLISH),
+        // Part 1 reads every digit, zero included.
+        Some("digits") => Lexicon::new(vec![("0".to_string(), 0)]),
+        Some(name) => Lexicon::load(name),
+    };
+    let contents = read_input();
+    let explanations = explain_lines(&lexicon, &contents);
+
+    for (i, explanation) in explanations.iter().enumerate() {
+        match (&explanation.matches, explanation.value()) {
+            (Some((first, last)), Some(value)) => println!(
+                "{:>5}: {} -> {} (first {}, last {})",
+                i + 1,
+                explanation.line,
+                value,
+                explanation.describe(first),
+                explanation.describe(last)
+            ),
+            _ => println!("{:>5}: {} -> NO DIGITS", i + 1, explanation.line),
+        }
+    }
+
+    let missing = explanations.iter().filter(|e| e.value().is_none()).count();
+    let total: u32 = explanations.iter().filter_map(|e| e.value()).sum();
+    println!("Total: {total} ({missing} lines without digits)");
+}
+
+pub fn run() {
+    println!("Part 1: {}", part1());
+    println!("Part 2: {}", part2());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(), 55123);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(), 55260);
+    }
+
+    #[test]
+    fn test_overlapping_matches() {
+        let matcher = Lexicon::builtin(&ENGLISH).matcher;
+        assert_eq!(
+            matcher.find_matches("xeightwo3"),
+            vec![
+                DigitMatch {
+                    start: 1,
+                    end: 6,
+                    value: 8
+                },
+                DigitMatch {
+                    start: 5,
+                    end: 8,
+                    value: 2
+                },
+                DigitMatch {
+                    start: 8,
+                    end: 9,
+                    value: 3
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lexicons() {
+        let german = Lexicon::load("german");
+        assert_eq!(calibration_value2(&german, "zweiundvierzig"), Some(24));
+        assert_eq!(calibration_value2(&german, "xfünfx"), Some(55));
+
+        let french = Lexicon::load("french");
+        assert_eq!(calibration_value2(&french, "huitcent3"), Some(83));
+
+        let teens = Lexicon::parse("# teens\nzero 0\nnine 9\nnineteen 19\n");
+        assert_eq!(calibration_value2(&teens, "nineteenx"), Some(19));
+        assert_eq!(calibration_value2(&teens, "zeronine"), Some(9));
+        assert_eq!(calibration_value2(&teens, "0ninezero0"), Some(90));
+
+        let english = Lexicon::builtin(&ENGLISH);
+        assert_eq!(calibration_value2(&english, "0two30"), Some(23));
+        assert_eq!(calibration_value2(&english, "x0x"), None);
+        assert_eq!(calibration_value1("0two30"), Some(0));
+    }
+
+    #[test]
+    fn test_explain_lines() {
+        let lexicon = Lexicon::builtin(&ENGLISH);
+        let explanations = explain_lines(&lexicon, "two1nine\nabc\n7\n");
+
+        let (first, last) = explanations[0].matches.as_ref().unwrap();
+        assert_eq!(explanations[0].describe(first), "\"two\" at 0..3");
+        assert_eq!(explanations[0].describe(last), "\"nine\" at 4..8");
+        assert_eq!(explanations[0].value(), Some(29));
+        assert_eq!(explanations[1].value(), None);
+        assert_eq!(calibration_value1("abc"), None);
+        assert_eq!(explanations[2].value(), Some(77));
+    }
+}
@@ -115,7 +115,7 @@ fn next_position(
 }
 
 fn parse_input() -> Vec<Vec<Pipe>> {
-    let contents = fs::read_to_string("./inputs/day10.txt").expect("No file found");
+    let contents = fs::read_to_string("./inputs/2023/day10.txt").expect("No file found");
     let lines = contents.split("\n");
 
     return lines
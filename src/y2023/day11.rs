@@ -27,7 +27,7 @@ fn parse_map(input: char) -> Entry {
 }
 
 fn parse_input() -> Universe {
-    let contents = fs::read_to_string("./inputs/day11.txt").expect("No file found");
+    let contents = fs::read_to_string("./inputs/2023/day11.txt").expect("No file found");
     let lines = contents.split("\n");
 
     return lines
@@ -47,7 +47,7 @@ fn parse_line(line: &str) -> Row {
 }
 
 fn parse_input() -> Vec<Row> {
-    let contents = fs::read_to_string("./inputs/day12.txt").expect("No file found");
+    let contents = fs::read_to_string("./inputs/2023/day12.txt").expect("No file found");
     let lines = contents.split("\n");
 
     return lines
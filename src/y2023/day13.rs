@@ -26,7 +26,7 @@ fn parse_puzzle(input: &str) -> Puzzle {
 }
 
 fn parse_input() -> Vec<Puzzle> {
-    let contents = fs::read_to_string("./inputs/day13.txt").expect("No file found");
+    let contents = fs::read_to_string("./inputs/2023/day13.txt").expect("No file found");
     let puzzles = contents.split("\n\n");
     return puzzles.map(parse_puzzle).collect();
 }
@@ -38,7 +38,7 @@ fn mirror_string(mirror: &Mirror) -> String {
 }
 
 fn parse_input() -> Mirror {
-    let contents = fs::read_to_string("./inputs/day14.txt").expect("No file found");
+    let contents = fs::read_to_string("./inputs/2023/day14.txt").expect("No file found");
     let lines = contents.split("\n");
 
     return lines
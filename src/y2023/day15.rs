@@ -4,7 +4,7 @@ use std::fs;
 // Common
 
 fn parse_input() -> Vec<String> {
-    let mut contents = fs::read_to_string("./inputs/day15.txt").expect("No file found");
+    let mut contents = fs::read_to_string("./inputs/2023/day15.txt").expect("No file found");
     if contents.ends_with('\n') {
         contents.pop();
     }
@@ -134,7 +134,7 @@ fn run_beam(contraption: &mut Contraption, row: i32, col: i32, direction: Direct
 }
 
 fn parse_input() -> Contraption {
-    let contents = fs::read_to_string("./inputs/day16.txt").expect("No file found");
+    let contents = fs::read_to_string("./inputs/2023/day16.txt").expect("No file found");
     let lines = contents.split("\n");
 
     return lines
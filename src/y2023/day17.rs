@@ -8,7 +8,7 @@ use std::fs;
 type CityMap = Vec<Vec<u32>>;
 
 fn parse_input() -> CityMap {
-    let contents = fs::read_to_string("./inputs/day17.txt").expect("No file found");
+    let contents = fs::read_to_string("./inputs/2023/day17.txt").expect("No file found");
     let lines = contents.split("\n");
 
     return lines
@@ -24,7 +24,7 @@ struct Instruction {
 }
 
 fn parse_input(parse_line: &dyn Fn(&str) -> Instruction) -> Vec<Instruction> {
-    let contents = fs::read_to_string("./inputs/day18.txt").expect("No file found");
+    let contents = fs::read_to_string("./inputs/2023/day18.txt").expect("No file found");
     let lines = contents.split("\n");
 
     return lines.filter(|l| l.len() > 0).map(parse_line).collect();
@@ -105,7 +105,7 @@ fn parse_part(input: &str) -> Part {
 }
 
 fn parse_input() -> (HashMap<String, Workflow>, Vec<Part>) {
-    let contents = fs::read_to_string("./inputs/day19.txt").expect("File not found");
+    let contents = fs::read_to_string("./inputs/2023/day19.txt").expect("File not found");
     let (workflows_str, ratings_str) = contents.split_once("\n\n").unwrap();
 
     let mut workflows = HashMap::new();
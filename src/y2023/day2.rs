@@ -0,0 +1,377 @@
+use std::collections::BTreeMap;
+use std::fs;
+
+// Common
+
+/// Cube counts by color. Colors that were never mentioned count as zero.
+#[derive(Debug, Default, PartialEq)]
+struct CubeSet {
+    counts: BTreeMap<String, u32>,
+}
+
+impl CubeSet {
+    fn count(&self, color: &str) -> u32 {
+        self.counts.get(color).copied().unwrap_or(0)
+    }
+}
+
+#[derive(Debug)]
+struct Game {
+    number: u32,
+    sets: Vec<CubeSet>,
+}
+
+fn parse_set(input: &str) -> CubeSet {
+    let mut counts = BTreeMap::new();
+
+    for entry in input.split(',').map(|e| e.trim()).filter(|e| !e.is_empty()) {
+        let (num, color) = entry.split_once(' ').unwrap();
+        *counts.entry(color.trim().to_string()).or_insert(0) += num.parse::<u32>().unwrap();
+    }
+
+    CubeSet { counts }
+}
+
+fn parse_game(input: &str) -> Game {
+    let (game, sets) = input.split_once(": ").unwrap();
+    let game_num: u32 = game.split(' ').last().unwrap().parse().unwrap();
+
+    return Game {
+        number: game_num,
+        sets: sets.split("; ").map(parse_set).collect(),
+    };
+}
+
+fn games() -> Vec<Game> {
+    let contents = fs::read_to_string("./inputs/2023/day2.txt").expect("No file found");
+    let lines = contents.split("\n");
+
+    return lines
+        .filter(|line| line.len() > 0)
+        .map(parse_game)
+        .collect();
+}
+
+// Part 1
+
+fn default_bag() -> CubeSet {
+    parse_set("12 red, 13 green, 14 blue")
+}
+
+/// The first place a game asks for more cubes of a color than the bag holds.
+#[derive(Debug, PartialEq)]
+struct Violation {
+    set_index: usize,
+    color: String,
+    count: u32,
+    limit: u32,
+}
+
+fn check_set(set: &CubeSet, bag: &CubeSet) -> Option<(String, u32, u32)> {
+    set.counts
+        .iter()
+        .find(|(color, count)| **count > bag.count(color))
+        .map(|(color, count)| (color.to_string(), *count, bag.count(color)))
+}
+
+fn check_game(game: &Game, bag: &CubeSet) -> Result<(), Violation> {
+    for (set_index, set) in game.sets.iter().enumerate() {
+        if let Some((color, count, limit)) = check_set(set, bag) {
+            return Err(Violation {
+                set_index,
+                color,
+                count,
+                limit,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn valid_game(game: &Game, bag: &CubeSet) -> bool {
+    check_game(game, bag).is_ok()
+}
+
+fn part1() -> u32 {
+    let bag = default_bag();
+
+    return games()
+        .into_iter()
+        .filter(|game| valid_game(game, &bag))
+        .map(|game| game.number)
+        .sum();
+}
+
+// Part 2
+
+fn get_cube_minimums(game: &Game) -> CubeSet {
+    let mut cube_minimums = CubeSet::default();
+
+    for set in game.sets.iter() {
+        for (color, count) in set.counts.iter() {
+            let minimum = cube_minimums.counts.entry(color.to_string()).or_insert(0);
+            *minimum = (*minimum).max(*count);
+        }
+    }
+
+    cube_minimums
+}
+
+/// The product of the minimums over every color in the palette, so a game
+/// that never shows a palette color has zero power.
+fn cube_power(set: &CubeSet, palette: &CubeSet) -> u32 {
+    palette
+        .counts
+        .keys()
+        .map(|color| set.count(color))
+        .product()
+}
+
+fn part2() -> u32 {
+    let palette = default_bag();
+
+    return games()
+        .iter()
+        .map(get_cube_minimums)
+        .map(|minimums| cube_power(&minimums, &palette))
+        .sum();
+}
+
+// Check
+
+/// Lists which games a bag allows, and for the rest the first set and color
+/// that rules them out. Takes the bag as e.g. "12 red, 13 green, 14 blue".
+pub fn check(args: &[String]) {
+    let bag = if args.is_empty() {
+        default_bag()
+    } else {
+        parse_set(&args.join(" "))
+    };
+
+    for game in games() {
+        match check_game(&game, &bag) {
+            Ok(()) => println!("Game {}: possible", game.number),
+            Err(violation) => println!(
+                "Game {}: impossible, set {} needs {} {} but the bag has {}",
+                game.number,
+                violation.set_index + 1,
+                violation.count,
+                violation.color,
+                violation.limit
+            ),
+        }
+    }
+}
+
+// Estimate
+
+/// The most likely bag behind a set of games, treating each set as cubes drawn
+/// without replacement and put back before the next set.
+#[derive(Debug)]
+struct BagEstimate {
+    bag: CubeSet,
+    log_likelihood: f64,
+    // 95% profile likelihood interval for each color, with no upper bound
+    // when the interval runs into the search limit
+    bounds: BTreeMap<String, (u32, Option<u32>)>,
+    // Largest count searched for each color
+    limits: BTreeMap<String, u32>,
+}
+
+fn ln_choose(ln_factorials: &[f64], n: u32, k: u32) -> f64 {
+    ln_factorials[n as usize] - ln_factorials[k as usize] - ln_factorials[(n - k) as usize]
+}
+
+fn set_log_likelihood(ln_factorials: &[f64], set: &CubeSet, bag: &[(&String, u32)]) -> f64 {
+    let total: u32 = bag.iter().map(|(_, n)| n).sum();
+    let drawn: u32 = set.counts.values().sum();
+
+    bag.iter()
+        .map(|(color, n)| ln_choose(ln_factorials, *n, set.count(color)))
+        .sum::<f64>()
+        - ln_choose(ln_factorials, total, drawn)
+}
+
+/// Searches every bag from the observed minimums up to `max_per_color` cubes
+/// of each color. The search is a full grid, so it grows quickly with the
+/// number of colors.
+fn estimate_bag(games: &[Game], max_per_color: u32) -> BagEstimate {
+    let sets: Vec<&CubeSet> = games.iter().flat_map(|g| g.sets.iter()).collect();
+    let mut minimums = CubeSet::default();
+    for game in games {
+        for (color, count) in get_cube_minimums(game).counts {
+            let minimum = minimums.counts.entry(color).or_insert(0);
+            *minimum = (*minimum).max(count);
+        }
+    }
+
+    let colors: Vec<&String> = minimums.counts.keys().collect();
+    let lowest: Vec<u32> = colors.iter().map(|c| minimums.count(c)).collect();
+    let highest: Vec<u32> = lowest.iter().map(|l| max_per_color.max(*l)).collect();
+
+    let max_total = highest.iter().sum::<u32>() as usize;
+    let mut ln_factorials = vec![0.0; max_total + 1];
+    for i in 1..=max_total {
+        ln_factorials[i] = ln_factorials[i - 1] + (i as f64).ln();
+    }
+
+    // Best log likelihood seen for each (color, count), for the bounds.
+    let mut profiles: Vec<BTreeMap<u32, f64>> = vec![BTreeMap::new(); colors.len()];
+    let mut best: Option<(f64, Vec<u32>)> = None;
+    let mut candidate = lowest.clone();
+
+    loop {
+        let bag: Vec<(&String, u32)> = colors
+            .iter()
+            .copied()
+            .zip(candidate.iter().copied())
+            .collect();
+        let log_likelihood: f64 = sets
+            .iter()
+            .map(|set| set_log_likelihood(&ln_factorials, set, &bag))
+            .sum();
+
+        for (profile, count) in profiles.iter_mut().zip(candidate.iter()) {
+            let entry = profile.entry(*count).or_insert(f64::NEG_INFINITY);
+            *entry = entry.max(log_likelihood);
+        }
+        if best.as_ref().is_none_or(|(b, _)| log_likelihood > *b) {
+            best = Some((log_likelihood, candidate.clone()));
+        }
+
+        // Step to the next candidate, odometer style.
+        let Some(position) = (0..candidate.len()).find(|i| candidate[*i] < highest[*i]) else {
+            break;
+        };
+        candidate[position] += 1;
+        candidate[..position].copy_from_slice(&lowest[..position]);
+    }
+
+    let (log_likelihood, counts) = best.unwrap_or((0.0, lowest));
+    // Half the 95% chi-squared quantile with one degree of freedom
+    let cutoff = log_likelihood - 1.92;
+    let bounds = colors
+        .iter()
+        .zip(profiles.iter())
+        .enumerate()
+        .map(|(i, (color, profile))| {
+            let inside: Vec<u32> = profile
+                .iter()
+                .filter(|(_, l)| **l >= cutoff)
+                .map(|(n, _)| *n)
+                .collect();
+            let high = inside[inside.len() - 1];
+            (
+                color.to_string(),
+                (inside[0], (high < highest[i]).then_some(high)),
+            )
+        })
+        .collect();
+
+    BagEstimate {
+        bag: CubeSet {
+            counts: colors.iter().map(|c| c.to_string()).zip(counts).collect(),
+        },
+        log_likelihood,
+        bounds,
+        limits: colors.iter().map(|c| c.to_string()).zip(highest).collect(),
+    }
+}
+
+/// Prints the maximum likelihood bag for the puzzle games, searching up to
+/// the given number of cubes per color (default 30).
+pub fn estimate(args: &[String]) {
+    let max_per_color = args
+        .first()
+        .map_or(30, |a| a.parse().expect("Invalid maximum"));
+    let estimate = estimate_bag(&games(), max_per_color);
+
+    println!("Log likelihood: {:.3}", estimate.log_likelihood);
+    for (color, count) in estimate.bag.counts.iter() {
+        match estimate.bounds[color] {
+            (low, Some(high)) => println!("{color}: {count} (95% interval {low}..={high})"),
+            (low, None) if *count == estimate.limits[color] => println!(
+                "{color}: still rising at the search limit of {count}, no upper bound (lower bound {low})"
+            ),
+            (low, None) => println!(
+                "{color}: {count} (lower bound {low}, the interval runs past the search limit of {})",
+                estimate.limits[color]
+            ),
+        }
+    }
+    if estimate.bounds.values().any(|(_, high)| high.is_none()) {
+        println!("Some colors ran into the search limit, try a larger one than {max_per_color}");
+    }
+}
+
+// Main
+
+pub fn run() {
+    println!("Part 1: {}", part1());
+    println!("Part 2: {}", part2());
+}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(), 2169);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(), 60948);
+    }
+
+    #[test]
+    fn test_check_game() {
+        let game = parse_game("Game 7: 3 red, 2 teal; 1 teal, 4 red; 5 mauve");
+        let bag = parse_set("4 red, 2 teal");
+
+        assert_eq!(
+            check_game(&game, &bag),
+            Err(Violation {
+                set_index: 2,
+                color: "mauve".to_string(),
+                count: 5,
+                limit: 0,
+            })
+        );
+        assert!(valid_game(&game, &parse_set("4 red, 2 teal, 5 mauve")));
+        assert_eq!(cube_power(&get_cube_minimums(&game), &game.sets[0]), 8);
+    }
+
+    #[test]
+    fn test_estimate_bag() {
+        let games = vec![
+            parse_game("Game 1: 1 red, 1 blue; 1 red, 1 blue"),
+            parse_game("Game 2: 1 blue, 1 red"),
+        ];
+        let estimate = estimate_bag(&games, 5);
+        assert_eq!(estimate.bag, parse_set("1 red, 1 blue"));
+        assert_eq!(estimate.log_likelihood, 0.0);
+        assert_eq!(estimate.bounds["red"], (1, None));
+
+        let games = vec![parse_game(
+            "Game 1: 4 red, 3 blue; 3 red, 4 blue; 4 red, 4 blue; 2 red, 4 blue",
+        )];
+        for max_per_color in [20, 30] {
+            let estimate = estimate_bag(&games, max_per_color);
+            assert_eq!(estimate.bag, parse_set("4 red, 4 blue"));
+            assert!((estimate.log_likelihood + 2.9267).abs() < 1e-4);
+            assert_eq!(estimate.bounds["red"], (4, Some(13)));
+            assert_eq!(estimate.bounds["blue"], (4, Some(15)));
+        }
+
+        let games = vec![parse_game("Game 1: 3 red; 2 red, 1 blue; 3 red; 3 red")];
+        let estimate = estimate_bag(&games, 12);
+        assert_eq!(estimate.bag, parse_set("11 red, 1 blue"));
+        assert_eq!(estimate.bounds["red"], (4, None));
+        assert_eq!(estimate.bounds["blue"], (1, Some(5)));
+    }
+}
@@ -182,7 +182,7 @@ fn parse_line(input: &str) -> Box<dyn Pulsable> {
 }
 
 fn parse_input() -> Vec<Box<dyn Pulsable>> {
-    let contents = fs::read_to_string("./inputs/day20.txt").expect("File not found");
+    let contents = fs::read_to_string("./inputs/2023/day20.txt").expect("File not found");
 
     return contents
         .split("\n")
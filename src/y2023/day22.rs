@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::fs;
+
+// Common
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Position {
+    x: usize,
+    y: usize,
+    z: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Brick {
+    start: Position,
+    end: Position,
+}
+
+impl Brick {
+    fn bottom(&self) -> usize {
+        self.start.z.min(self.end.z)
+    }
+
+    fn height(&self) -> usize {
+        self.start.z.abs_diff(self.end.z) + 1
+    }
+
+    fn footprint(&self) -> Vec<(usize, usize)> {
+        let (x_min, x_max) = (self.start.x.min(self.end.x), self.start.x.max(self.end.x));
+        let (y_min, y_max) = (self.start.y.min(self.end.y), self.start.y.max(self.end.y));
+
+        (x_min..=x_max)
+            .flat_map(|x| (y_min..=y_max).map(move |y| (x, y)))
+            .collect()
+    }
+}
+
+fn parse_position(input: &str) -> Position {
+    let coords: Vec<usize> = input
+        .split(',')
+        .map(|n| n.trim().parse().expect("Invalid coordinate"))
+        .collect();
+
+    Position {
+        x: coords[0],
+        y: coords[1],
+        z: coords[2],
+    }
+}
+
+fn parse_line(line: &str) -> Brick {
+    let (start, end) = line.split_once('~').expect("Invalid brick");
+
+    Brick {
+        start: parse_position(start),
+        end: parse_position(end),
+    }
+}
+
+fn parse_input() -> Vec<Brick> {
+    let contents = fs::read_to_string("./inputs/2023/day22.txt").expect("No file found");
+
+    contents
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(parse_line)
+        .collect()
+}
+
+/// Which bricks rest on which once everything has settled. Indices match the
+/// input order.
+#[derive(Debug)]
+struct SupportGraph {
+    supports: Vec<HashSet<usize>>,
+    supported_by: Vec<HashSet<usize>>,
+}
+
+impl SupportGraph {
+    fn len(&self) -> usize {
+        self.supports.len()
+    }
+
+    fn can_disintegrate(&self, brick: usize) -> bool {
+        self.supports[brick]
+            .iter()
+            .all(|above| self.supported_by[*above].len() > 1)
+    }
+
+    /// Every other brick that would fall if `brick` were removed.
+    fn falling_if_removed(&self, brick: usize) -> HashSet<usize> {
+        let mut fallen = HashSet::from([brick]);
+        let mut queue = VecDeque::from([brick]);
+
+        while let Some(current) = queue.pop_front() {
+            for above in self.supports[current].iter() {
+                if !fallen.contains(above) && self.supported_by[*above].is_subset(&fallen) {
+                    fallen.insert(*above);
+                    queue.push_back(*above);
+                }
+            }
+        }
+
+        fallen.remove(&brick);
+        fallen
+    }
+}
+
+/// Drops every brick as far as it goes, lowest first. The settled bricks and
+/// the graph keep the input order.
+fn settle(bricks: &[Brick]) -> (Vec<Brick>, SupportGraph) {
+    let mut order: Vec<usize> = (0..bricks.len()).collect();
+    order.sort_by_key(|i| bricks[*i].bottom());
+
+    // (x, y) -> (top z, brick index)
+    let mut height_map: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+    let mut supports = vec![HashSet::new(); bricks.len()];
+    let mut supported_by = vec![HashSet::new(); bricks.len()];
+    let mut settled = bricks.to_vec();
+
+    for index in order {
+        let brick = &bricks[index];
+        let footprint = brick.footprint();
+        let rest_on = footprint
+            .iter()
+            .filter_map(|p| height_map.get(p).map(|(z, _)| *z))
+            .max()
+            .unwrap_or(0);
+
+        for p in footprint.iter() {
+            if let Some((z, below)) = height_map.get(p) {
+                if *z == rest_on {
+                    supports[*below].insert(index);
+                    supported_by[index].insert(*below);
+                }
+            }
+        }
+
+        let drop = brick.bottom() - (rest_on + 1);
+        let fallen = Brick {
+            start: Position {
+                z: brick.start.z - drop,
+                ..brick.start
+            },
+            end: Position {
+                z: brick.end.z - drop,
+                ..brick.end
+            },
+        };
+        let top = fallen.bottom() + fallen.height() - 1;
+        for p in footprint {
+            height_map.insert(p, (top, index));
+        }
+        settled[index] = fallen;
+    }
+
+    (
+        settled,
+        SupportGraph {
+            supports,
+            supported_by,
+        },
+    )
+}
+
+// Part 1
+
+fn part1() -> usize {
+    let (_, graph) = settle(&parse_input());
+
+    (0..graph.len())
+        .filter(|b| graph.can_disintegrate(*b))
+        .count()
+}
+
+// Part 2
+
+fn part2() -> usize {
+    let (_, graph) = settle(&parse_input());
+
+    (0..graph.len())
+        .map(|b| graph.falling_if_removed(b).len())
+        .sum()
+}
+
+// Falling
+
+/// Lists the bricks that fall if the brick on the given input line (counting
+/// from 1) is removed.
+pub fn falling(args: &[String]) {
+    let line: usize = args
+        .first()
+        .expect("Usage: 22 falling LINE")
+        .parse()
+        .expect("Invalid line");
+    let (settled, graph) = settle(&parse_input());
+    assert!((1..=graph.len()).contains(&line), "No brick on line {line}");
+
+    let mut fallen: Vec<usize> = graph.falling_if_removed(line - 1).into_iter().collect();
+    fallen.sort();
+    println!(
+        "Removing the brick on line {line} (settled at z={}) drops {} bricks",
+        settled[line - 1].bottom(),
+        fallen.len()
+    );
+    for brick in fallen {
+        println!(
+            "  line {} (settled at z={})",
+            brick + 1,
+            settled[brick].bottom()
+        );
+    }
+}
+
+// Main
+
+pub fn run() {
+    println!("Part 1: {}", part1());
+    println!("Part 2: {}", part2());
+}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(), 5);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(), 7);
+    }
+
+    #[test]
+    fn test_falling_if_removed() {
+        let (settled, graph) = settle(&parse_input());
+        assert_eq!(settled[0].bottom(), 1);
+        assert_eq!(graph.falling_if_removed(0).len(), 6);
+        assert_eq!(graph.falling_if_removed(5), HashSet::from([6]));
+    }
+
+    #[test]
+    fn test_settle_keeps_input_order() {
+        let mut bricks = parse_input();
+        bricks.reverse();
+        let (settled, graph) = settle(&bricks);
+
+        // The example's first brick, A, is now last and still holds up the rest.
+        assert_eq!(settled[6].bottom(), 1);
+        assert_eq!(settled[0].bottom(), 5);
+        assert_eq!(graph.falling_if_removed(6).len(), 6);
+        assert_eq!(graph.falling_if_removed(1), HashSet::from([0]));
+    }
+}
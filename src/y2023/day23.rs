@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+use std::fs;
+
+// Common
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::North,
+    Direction::South,
+    Direction::East,
+    Direction::West,
+];
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Tile {
+    Path,
+    Forest,
+    Slope(Direction),
+}
+
+type TrailMap = Vec<Vec<Tile>>;
+
+fn parse_tile(tile: char) -> Tile {
+    match tile {
+        '.' => Tile::Path,
+        '#' => Tile::Forest,
+        '^' => Tile::Slope(Direction::North),
+        'v' => Tile::Slope(Direction::South),
+        '>' => Tile::Slope(Direction::East),
+        '<' => Tile::Slope(Direction::West),
+        _ => panic!("Unknown tile {tile}"),
+    }
+}
+
+fn parse_input() -> TrailMap {
+    let contents = fs::read_to_string("./inputs/2023/day23.txt").expect("No file found");
+
+    contents
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| l.chars().map(parse_tile).collect())
+        .collect()
+}
+
+fn step(
+    map: &TrailMap,
+    (row, col): (usize, usize),
+    direction: Direction,
+) -> Option<(usize, usize)> {
+    let next = match direction {
+        Direction::North if row > 0 => (row - 1, col),
+        Direction::South if row + 1 < map.len() => (row + 1, col),
+        Direction::East if col + 1 < map[row].len() => (row, col + 1),
+        Direction::West if col > 0 => (row, col - 1),
+        _ => return None,
+    };
+
+    if map[next.0][next.1] == Tile::Forest {
+        return None;
+    }
+    Some(next)
+}
+
+fn can_leave(
+    map: &TrailMap,
+    (row, col): (usize, usize),
+    direction: Direction,
+    slippery: bool,
+) -> bool {
+    match map[row][col] {
+        Tile::Slope(slope) if slippery => slope == direction,
+        _ => true,
+    }
+}
+
+/// The trail map compressed down to its junctions (plus the start and end),
+/// with each edge weighted by the number of steps along the corridor between
+/// two junctions.
+#[derive(Debug)]
+struct TrailGraph {
+    junctions: Vec<(usize, usize)>,
+    edges: Vec<Vec<(usize, usize)>>,
+    start: usize,
+    end: usize,
+}
+
+impl TrailGraph {
+    fn new(map: &TrailMap, slippery: bool) -> TrailGraph {
+        let last_row = map.len() - 1;
+        let start_col = map[0].iter().position(|t| *t != Tile::Forest).unwrap();
+        let end_col = map[last_row]
+            .iter()
+            .position(|t| *t != Tile::Forest)
+            .unwrap();
+
+        let mut junctions = vec![(0, start_col), (last_row, end_col)];
+        for (row, tiles) in map.iter().enumerate() {
+            for (col, tile) in tiles.iter().enumerate() {
+                let exits = DIRECTIONS
+                    .iter()
+                    .filter(|d| step(map, (row, col), **d).is_some())
+                    .count();
+                if *tile != Tile::Forest && exits > 2 {
+                    junctions.push((row, col));
+                }
+            }
+        }
+
+        let index: HashMap<(usize, usize), usize> =
+            junctions.iter().enumerate().map(|(i, p)| (*p, i)).collect();
+
+        let edges = junctions
+            .iter()
+            .map(|junction| {
+                DIRECTIONS
+                    .iter()
+                    .filter_map(|d| follow_corridor(map, &index, *junction, *d, slippery))
+                    .collect()
+            })
+            .collect();
+
+        TrailGraph {
+            junctions,
+            edges,
+            start: 0,
+            end: 1,
+        }
+    }
+
+    /// Length of the longest path from start to end that never revisits a
+    /// junction.
+    fn longest_path(&self) -> Option<usize> {
+        assert!(
+            self.junctions.len() <= 64,
+            "Too many junctions for a u64 visited set"
+        );
+
+        fn search(graph: &TrailGraph, node: usize, visited: u64) -> Option<usize> {
+            if node == graph.end {
+                return Some(0);
+            }
+
+            graph.edges[node]
+                .iter()
+                .filter(|(next, _)| visited & (1 << next) == 0)
+                .filter_map(|(next, weight)| {
+                    search(graph, *next, visited | (1 << next)).map(|rest| rest + weight)
+                })
+                .max()
+        }
+
+        search(self, self.start, 1 << self.start)
+    }
+
+    fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph trails {\n");
+        for (i, (row, col)) in self.junctions.iter().enumerate() {
+            dot.push_str(&format!("  {i} [label=\"{row},{col}\"];\n"));
+        }
+        for (from, edges) in self.edges.iter().enumerate() {
+            for (to, weight) in edges {
+                dot.push_str(&format!("  {from} -> {to} [label=\"{weight}\"];\n"));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn follow_corridor(
+    map: &TrailMap,
+    junctions: &HashMap<(usize, usize), usize>,
+    from: (usize, usize),
+    direction: Direction,
+    slippery: bool,
+) -> Option<(usize, usize)> {
+    if !can_leave(map, from, direction, slippery) {
+        return None;
+    }
+
+    let mut previous = from;
+    let mut current = step(map, from, direction)?;
+    let mut steps = 1;
+
+    while !junctions.contains_key(&current) {
+        let next = DIRECTIONS
+            .iter()
+            .filter(|d| can_leave(map, current, **d, slippery))
+            .filter_map(|d| step(map, current, *d))
+            .find(|p| *p != previous)?;
+        previous = current;
+        current = next;
+        steps += 1;
+    }
+
+    Some((junctions[&current], steps))
+}
+
+// Part 1
+
+fn part1() -> usize {
+    let graph = TrailGraph::new(&parse_input(), true);
+    graph.longest_path().expect("No path found")
+}
+
+// Part 2
+
+fn part2() -> usize {
+    let graph = TrailGraph::new(&parse_input(), false);
+    graph.longest_path().expect("No path found")
+}
+
+// Dot
+
+/// Prints the junction graph as Graphviz DOT, treating slopes as plain paths
+/// as in part 2 unless given `slippery`.
+pub fn dot(args: &[String]) {
+    let slippery = args.iter().any(|a| a == "slippery");
+    print!("{}", TrailGraph::new(&parse_input(), slippery).to_dot());
+}
+
+// Main
+
+pub fn run() {
+    println!("Part 1: {}", part1());
+    println!("Part 2: {}", part2());
+}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(), 94);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(), 154);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let graph = TrailGraph::new(&parse_input(), false);
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph trails {\n  0 [label=\"0,1\"];\n"));
+        assert_eq!(dot.matches("->").count(), 2 * 12);
+    }
+}
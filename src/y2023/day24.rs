@@ -0,0 +1,281 @@
+use std::fs;
+
+use crate::common::rational::{gcd, solve_linear, Rational};
+
+// Common
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Hailstone {
+    position: [i128; 3],
+    velocity: [i128; 3],
+}
+
+fn parse_triple(input: &str) -> [i128; 3] {
+    let values: Vec<i128> = input
+        .split(',')
+        .map(|n| n.trim().parse().expect("Invalid number"))
+        .collect();
+
+    [values[0], values[1], values[2]]
+}
+
+fn parse_line(line: &str) -> Hailstone {
+    let (position, velocity) = line.split_once('@').expect("Invalid hailstone");
+
+    Hailstone {
+        position: parse_triple(position),
+        velocity: parse_triple(velocity),
+    }
+}
+
+fn parse_input() -> Vec<Hailstone> {
+    let contents = fs::read_to_string("./inputs/2023/day24.txt").expect("No file found");
+
+    contents
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(parse_line)
+        .collect()
+}
+
+// Part 1
+
+const TEST_AREA: (i128, i128) = (200000000000000, 400000000000000);
+
+/// Where the XY paths of two hailstones cross, if they cross in the future
+/// of both.
+fn path_intersection(a: &Hailstone, b: &Hailstone) -> Option<(Rational, Rational)> {
+    let [ax, ay, _] = a.position;
+    let [avx, avy, _] = a.velocity;
+    let [bx, by, _] = b.position;
+    let [bvx, bvy, _] = b.velocity;
+
+    let det = bvx * avy - avx * bvy;
+    if det == 0 {
+        return None;
+    }
+
+    let (dx, dy) = (bx - ax, by - ay);
+    let t = Rational::new(bvx * dy - bvy * dx, det);
+    let s = Rational::new(avx * dy - avy * dx, det);
+    if t < Rational::integer(0) || s < Rational::integer(0) {
+        return None;
+    }
+
+    Some((
+        Rational::integer(ax) + t * Rational::integer(avx),
+        Rational::integer(ay) + t * Rational::integer(avy),
+    ))
+}
+
+fn count_intersections(hailstones: &[Hailstone], min: i128, max: i128) -> usize {
+    let (min, max) = (Rational::integer(min), Rational::integer(max));
+    let in_area = |v: Rational| min <= v && v <= max;
+
+    hailstones
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| hailstones[i + 1..].iter().map(move |b| (a, b)))
+        .filter_map(|(a, b)| path_intersection(a, b))
+        .filter(|(x, y)| in_area(*x) && in_area(*y))
+        .count()
+}
+
+fn part1() -> usize {
+    count_intersections(&parse_input(), TEST_AREA.0, TEST_AREA.1)
+}
+
+// Part 2
+
+fn sub(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn collides(rock: &Hailstone, hailstone: &Hailstone) -> bool {
+    let offset = sub(hailstone.position, rock.position);
+    let closing = sub(rock.velocity, hailstone.velocity);
+
+    // Both must be the same whole number of steps apart on every axis.
+    let mut time = None;
+    for (d, v) in offset.into_iter().zip(closing) {
+        if v == 0 {
+            if d != 0 {
+                return false;
+            }
+        } else if d % v != 0 || time.is_some_and(|t| t != d / v) {
+            return false;
+        } else {
+            time = Some(d / v);
+        }
+    }
+    time.is_none_or(|t| t >= 0)
+}
+
+fn gcd3(v: [i128; 3]) -> i128 {
+    gcd(gcd(v[0], v[1]), v[2])
+}
+
+/// Seen from the first hailstone, every other path and the rock's path lie in
+/// a plane through the origin, so the rock's direction `d` is perpendicular to
+/// each plane's normal `p_i x v_i`. The rock is then `s * d` moving at `m * d`,
+/// and `(P - p_i) x (V - v_i) = 0` becomes linear in `s` and `m`:
+/// `s * (d x v_i) + m * (p_i x d) = p_i x v_i`. Keeping the unknowns to those
+/// two scalars keeps every minor within i128 at puzzle scale, and the rock is
+/// then checked against every hailstone.
+fn find_rock(hailstones: &[Hailstone]) -> Option<Hailstone> {
+    assert!(hailstones.len() >= 3, "Need at least three hailstones");
+
+    let first = hailstones[0];
+    let relative: Vec<Hailstone> = hailstones
+        .iter()
+        .map(|h| Hailstone {
+            position: sub(h.position, first.position),
+            velocity: sub(h.velocity, first.velocity),
+        })
+        .collect();
+    let normals: Vec<[i128; 3]> = relative
+        .iter()
+        .map(|h| cross(h.position, h.velocity))
+        .collect();
+
+    let direction = (1..relative.len())
+        .flat_map(|i| (i + 1..relative.len()).map(move |j| (i, j)))
+        .map(|(i, j)| cross(normals[i], normals[j]))
+        .find(|d| *d != [0, 0, 0])?;
+    let direction = direction.map(|c| c / gcd3(direction));
+
+    // Three equations per hailstone; any two independent ones will do.
+    let equations: Vec<([i128; 2], i128)> = relative[1..]
+        .iter()
+        .flat_map(|h| {
+            let along = cross(direction, h.velocity);
+            let across = cross(h.position, direction);
+            let target = cross(h.position, h.velocity);
+            (0..3).map(move |axis| ([along[axis], across[axis]], target[axis]))
+        })
+        .collect();
+    let (scale, speed) = equations
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| equations[i + 1..].iter().map(move |b| (a, b)))
+        .find_map(|(a, b)| solve_linear(&[a.0.to_vec(), b.0.to_vec()], &[a.1, b.1]))
+        .map(|solution| (solution[0].to_integer(), solution[1].to_integer()))?;
+    let (scale, speed) = (scale?, speed?);
+
+    let rock = Hailstone {
+        position: [0, 1, 2].map(|i| first.position[i] + scale * direction[i]),
+        velocity: [0, 1, 2].map(|i| first.velocity[i] + speed * direction[i]),
+    };
+
+    hailstones
+        .iter()
+        .all(|h| collides(&rock, h))
+        .then_some(rock)
+}
+
+fn part2() -> i128 {
+    let rock = find_rock(&parse_input()).expect("No rock trajectory found");
+    rock.position.iter().sum()
+}
+
+// Main
+
+pub fn run() {
+    println!("Part 1: {}", part1());
+    println!("Part 2: {}", part2());
+}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(count_intersections(&parse_input(), 7, 27), 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(), 47);
+    }
+
+    #[test]
+    fn test_find_rock_large_coordinates() {
+        let rock = Hailstone {
+            position: [287430900794986, 361395084140445, 253209514255849],
+            velocity: [-137, -281, 152],
+        };
+        let hailstones: Vec<Hailstone> = [
+            (915231123, [58, -120, 31]),
+            (487120933, [-245, 17, -88]),
+            (771034502, [12, 233, -164]),
+            (103948771, [-61, -302, 275]),
+            (642019384, [190, 44, -7]),
+        ]
+        .iter()
+        .map(|(t, velocity)| Hailstone {
+            position: [0, 1, 2].map(|i| rock.position[i] + t * rock.velocity[i] - t * velocity[i]),
+            velocity: *velocity,
+        })
+        .collect();
+
+        assert_eq!(find_rock(&hailstones), Some(rock));
+    }
+
+    #[test]
+    fn test_find_rock_puzzle_scale() {
+        let rock = Hailstone {
+            position: [305176281944012, 287331902184476, 311930466207335],
+            velocity: [-96, 41, 127],
+        };
+        let hailstones: Vec<Hailstone> = [
+            (912304178231, [-301, 214, -71]),
+            (487120933102, [198, -247, 322]),
+            (771034502816, [-14, 282, 346]),
+            (103948771540, [881, -890, 726]),
+            (642019384207, [-359, 320, 413]),
+            (998102647113, [101, -119, -58]),
+        ]
+        .iter()
+        .map(|(t, velocity)| Hailstone {
+            position: [0, 1, 2].map(|i| rock.position[i] + t * rock.velocity[i] - t * velocity[i]),
+            velocity: *velocity,
+        })
+        .collect();
+
+        for h in hailstones.iter() {
+            assert!(h
+                .position
+                .iter()
+                .all(|p| (1e14 as i128..5e14 as i128).contains(p)));
+        }
+        assert_eq!(find_rock(&hailstones), Some(rock));
+
+        // One unit off and no whole-number rock hits every hailstone.
+        let mut nudged = hailstones.clone();
+        nudged[5].position[0] += 1;
+        assert_eq!(find_rock(&nudged), None);
+    }
+
+    #[test]
+    fn test_solve_linear() {
+        let matrix = [vec![2, 1, -1], vec![-3, -1, 2], vec![-2, 1, 2]];
+        let solution = solve_linear(&matrix, &[8, -11, -3]).unwrap();
+        assert_eq!(solution, [2, 3, -1].map(Rational::integer));
+
+        let halves = solve_linear(&[vec![2, 0], vec![0, 4]], &[1, 2]).unwrap();
+        assert_eq!(halves, [Rational::new(1, 2), Rational::new(1, 2)]);
+
+        assert_eq!(solve_linear(&[vec![1, 2], vec![2, 4]], &[3, 6]), None);
+    }
+}
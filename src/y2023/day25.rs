@@ -0,0 +1,194 @@
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fs;
+
+// Common
+
+#[derive(Debug)]
+struct Wiring {
+    names: Vec<String>,
+    edges: Vec<(usize, usize)>,
+}
+
+fn parse_input() -> Wiring {
+    let contents = fs::read_to_string("./inputs/2023/day25.txt").expect("No file found");
+
+    let mut names: Vec<String> = Vec::new();
+    let mut ids: HashMap<String, usize> = HashMap::new();
+    let mut intern = |name: &str| -> usize {
+        *ids.entry(name.to_string()).or_insert_with(|| {
+            names.push(name.to_string());
+            names.len() - 1
+        })
+    };
+
+    let mut edges = Vec::new();
+    for line in contents.lines().filter(|l| !l.is_empty()) {
+        let (component, others) = line.split_once(':').expect("Invalid wiring");
+        let from = intern(component.trim());
+        for other in others.split_whitespace() {
+            edges.push((from, intern(other)));
+        }
+    }
+
+    Wiring { names, edges }
+}
+
+#[derive(Debug)]
+struct MinCut {
+    weight: u32,
+    side: HashSet<usize>,
+    cut_edges: Vec<(usize, usize)>,
+}
+
+/// Stoer-Wagner global minimum cut over the unweighted wiring graph.
+fn minimum_cut(wiring: &Wiring) -> MinCut {
+    let size = wiring.names.len();
+    let mut adjacency: Vec<HashMap<usize, u32>> = vec![HashMap::new(); size];
+    for (a, b) in wiring.edges.iter() {
+        *adjacency[*a].entry(*b).or_insert(0) += 1;
+        *adjacency[*b].entry(*a).or_insert(0) += 1;
+    }
+
+    // The original components that each merged vertex stands for.
+    let mut members: Vec<Vec<usize>> = (0..size).map(|v| vec![v]).collect();
+    let mut active: HashSet<usize> = (0..size).collect();
+    let mut best: Option<(u32, Vec<usize>)> = None;
+
+    while active.len() > 1 {
+        let start = *active.iter().next().unwrap();
+        let mut added: HashSet<usize> = HashSet::new();
+        let mut weights: HashMap<usize, u32> = HashMap::new();
+        let mut queue = BinaryHeap::from([(0, start)]);
+        let mut order = Vec::new();
+        let mut last_weight = 0;
+
+        while let Some((weight, vertex)) = queue.pop() {
+            if added.contains(&vertex) || weights.get(&vertex).copied().unwrap_or(0) != weight {
+                continue;
+            }
+            added.insert(vertex);
+            order.push(vertex);
+            last_weight = weight;
+
+            for (neighbor, w) in adjacency[vertex].iter() {
+                if !added.contains(neighbor) {
+                    let total = weights.entry(*neighbor).or_insert(0);
+                    *total += w;
+                    queue.push((*total, *neighbor));
+                }
+            }
+        }
+
+        // A phase only reaches its own component, which is then a free cut.
+        if order.len() < active.len() {
+            let side = order.iter().flat_map(|v| members[*v].clone()).collect();
+            best = Some((0, side));
+            break;
+        }
+
+        let t = order.pop().unwrap();
+        let s = order.pop().unwrap();
+        if best.as_ref().is_none_or(|(w, _)| last_weight < *w) {
+            best = Some((last_weight, members[t].clone()));
+        }
+
+        // Merge t into s.
+        let t_edges = std::mem::take(&mut adjacency[t]);
+        for (neighbor, w) in t_edges {
+            adjacency[neighbor].remove(&t);
+            if neighbor != s {
+                *adjacency[s].entry(neighbor).or_insert(0) += w;
+                *adjacency[neighbor].entry(s).or_insert(0) += w;
+            }
+        }
+        let t_members = std::mem::take(&mut members[t]);
+        members[s].extend(t_members);
+        active.remove(&t);
+    }
+
+    let (weight, side) = best.expect("Need at least two components");
+    let side: HashSet<usize> = side.into_iter().collect();
+    let cut_edges = wiring
+        .edges
+        .iter()
+        .filter(|(a, b)| side.contains(a) != side.contains(b))
+        .copied()
+        .collect();
+
+    MinCut {
+        weight,
+        side,
+        cut_edges,
+    }
+}
+
+// Part 1
+
+fn part1() -> usize {
+    let wiring = parse_input();
+    let cut = minimum_cut(&wiring);
+
+    cut.side.len() * (wiring.names.len() - cut.side.len())
+}
+
+// Main
+
+pub fn run() {
+    println!("Part 1: {}", part1());
+
+    let wiring = parse_input();
+    let cut = minimum_cut(&wiring);
+    let wires: Vec<String> = cut
+        .cut_edges
+        .iter()
+        .map(|(a, b)| format!("{}/{}", wiring.names[*a], wiring.names[*b]))
+        .collect();
+    println!("Cut {} wires: {}", cut.weight, wires.join(", "));
+}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(), 54);
+    }
+
+    #[test]
+    fn test_cut_edges() {
+        let wiring = parse_input();
+        let cut = minimum_cut(&wiring);
+        let mut edges: Vec<String> = cut
+            .cut_edges
+            .iter()
+            .map(|(a, b)| {
+                let mut pair = [&wiring.names[*a], &wiring.names[*b]];
+                pair.sort();
+                format!("{}/{}", pair[0], pair[1])
+            })
+            .collect();
+        edges.sort();
+
+        assert_eq!(cut.weight, 3);
+        assert_eq!(edges, ["bvb/cmg", "hfx/pzl", "jqt/nvd"]);
+    }
+
+    #[test]
+    fn test_disconnected_wiring() {
+        let wiring = Wiring {
+            names: ["a", "b", "c", "d", "e", "f"].map(String::from).to_vec(),
+            edges: vec![(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)],
+        };
+        let cut = minimum_cut(&wiring);
+
+        assert_eq!(cut.weight, 0);
+        assert_eq!(cut.side.len(), 3);
+        assert!(cut.cut_edges.is_empty());
+        assert!(cut.side.contains(&0) == cut.side.contains(&1));
+    }
+}
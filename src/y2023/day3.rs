@@ -0,0 +1,295 @@
+use std::collections::HashMap;
+use std::fs;
+
+// Common
+
+#[derive(Debug, PartialEq)]
+struct PartNumber {
+    value: u32,
+    row: usize,
+    // Columns start..end, end exclusive
+    start: usize,
+    end: usize,
+}
+
+#[derive(Debug, PartialEq)]
+struct Symbol {
+    char: char,
+    row: usize,
+    col: usize,
+}
+
+/// The engine schematic parsed once into numbers and symbols, with the
+/// adjacency between them kept in both directions by index.
+#[derive(Debug)]
+struct Schematic {
+    numbers: Vec<PartNumber>,
+    symbols: Vec<Symbol>,
+    symbol_numbers: Vec<Vec<usize>>,
+    number_symbols: Vec<Vec<usize>>,
+}
+
+fn is_symbol(char: char) -> bool {
+    !char.is_ascii_digit() && char != '.'
+}
+
+impl Schematic {
+    fn parse(input: &str) -> Schematic {
+        let mut numbers = Vec::new();
+        let mut symbols = Vec::new();
+
+        for (row, line) in input.lines().filter(|l| !l.is_empty()).enumerate() {
+            let chars: Vec<char> = line.chars().collect();
+            let mut col = 0;
+            while col < chars.len() {
+                if chars[col].is_ascii_digit() {
+                    let start = col;
+                    while col < chars.len() && chars[col].is_ascii_digit() {
+                        col += 1;
+                    }
+                    let value = chars[start..col]
+                        .iter()
+                        .collect::<String>()
+                        .parse()
+                        .unwrap();
+                    numbers.push(PartNumber {
+                        value,
+                        row,
+                        start,
+                        end: col,
+                    });
+                    continue;
+                }
+                if is_symbol(chars[col]) {
+                    symbols.push(Symbol {
+                        char: chars[col],
+                        row,
+                        col,
+                    });
+                }
+                col += 1;
+            }
+        }
+
+        let positions: HashMap<(usize, usize), usize> = symbols
+            .iter()
+            .enumerate()
+            .map(|(i, s)| ((s.row, s.col), i))
+            .collect();
+        let mut symbol_numbers = vec![Vec::new(); symbols.len()];
+        let mut number_symbols = vec![Vec::new(); numbers.len()];
+
+        for (n, number) in numbers.iter().enumerate() {
+            for row in number.row.saturating_sub(1)..=number.row + 1 {
+                for col in number.start.saturating_sub(1)..=number.end {
+                    if let Some(s) = positions.get(&(row, col)) {
+                        symbol_numbers[*s].push(n);
+                        number_symbols[n].push(*s);
+                    }
+                }
+            }
+        }
+
+        Schematic {
+            numbers,
+            symbols,
+            symbol_numbers,
+            number_symbols,
+        }
+    }
+
+    fn adjacent_numbers(&self, symbol: usize) -> impl Iterator<Item = &PartNumber> {
+        self.symbol_numbers[symbol]
+            .iter()
+            .map(|n| &self.numbers[*n])
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Aggregate {
+    Sum,
+    Product,
+    Count,
+}
+
+/// Selects symbols of one character by how many numbers touch them, and
+/// folds each one's numbers with an aggregate.
+#[derive(Debug)]
+struct SymbolQuery {
+    symbol: char,
+    min_arity: usize,
+    max_arity: Option<usize>,
+    aggregate: Aggregate,
+}
+
+impl SymbolQuery {
+    fn matches(&self, symbol: &Symbol, arity: usize) -> bool {
+        symbol.char == self.symbol
+            && arity >= self.min_arity
+            && self.max_arity.is_none_or(|max| arity <= max)
+    }
+
+    fn apply(&self, values: &[u32]) -> u64 {
+        let values = values.iter().map(|v| *v as u64);
+        match self.aggregate {
+            Aggregate::Sum => values.sum(),
+            Aggregate::Product => values.product(),
+            Aggregate::Count => values.count() as u64,
+        }
+    }
+}
+
+impl Schematic {
+    /// Each matching symbol with its adjacent part values and their aggregate.
+    fn query(&self, query: &SymbolQuery) -> Vec<(&Symbol, Vec<u32>, u64)> {
+        (0..self.symbols.len())
+            .filter(|s| query.matches(&self.symbols[*s], self.symbol_numbers[*s].len()))
+            .map(|s| {
+                let values: Vec<u32> = self.adjacent_numbers(s).map(|n| n.value).collect();
+                let result = query.apply(&values);
+                (&self.symbols[s], values, result)
+            })
+            .collect()
+    }
+}
+
+fn parse_input() -> Schematic {
+    let contents = fs::read_to_string("./inputs/2023/day3.txt").expect("No file found");
+    Schematic::parse(&contents)
+}
+
+// Part 1
+
+fn part1() -> u32 {
+    let schematic = parse_input();
+
+    schematic
+        .numbers
+        .iter()
+        .zip(schematic.number_symbols.iter())
+        .filter(|(_, symbols)| !symbols.is_empty())
+        .map(|(number, _)| number.value)
+        .sum()
+}
+
+// Part 2
+
+fn part2() -> u64 {
+    let gears = SymbolQuery {
+        symbol: '*',
+        min_arity: 2,
+        max_arity: Some(2),
+        aggregate: Aggregate::Product,
+    };
+
+    parse_input().query(&gears).iter().map(|(_, _, r)| r).sum()
+}
+
+// Query
+
+// Arity as "2", "3..", "..4" or "2..4"
+fn parse_arity(input: &str) -> (usize, Option<usize>) {
+    let parse = |n: &str| n.parse().expect("Invalid arity");
+
+    match input.split_once("..") {
+        Some((min, max)) => (
+            if min.is_empty() { 0 } else { parse(min) },
+            if max.is_empty() {
+                None
+            } else {
+                Some(parse(max))
+            },
+        ),
+        None => (parse(input), Some(parse(input))),
+    }
+}
+
+/// Runs a symbol query over the puzzle schematic, e.g. `'#' count 3..` for the
+/// `#` symbols touching three or more parts, or `'*' product 2` for the gear
+/// ratios. Symbols need quoting in the shell. The aggregate defaults to sum and
+/// the arity to any.
+pub fn query(args: &[String]) {
+    let symbol = args
+        .first()
+        .and_then(|a| a.chars().next())
+        .expect("Usage: query 'SYMBOL' [sum|product|count] [ARITY]");
+    let aggregate = match args.get(1).map(|a| a.as_str()) {
+        None | Some("sum") => Aggregate::Sum,
+        Some("product") => Aggregate::Product,
+        Some("count") => Aggregate::Count,
+        Some(other) => panic!("Unknown aggregate {other}"),
+    };
+    let (min_arity, max_arity) = args.get(2).map_or((0, None), |a| parse_arity(a));
+
+    let schematic = parse_input();
+    let results = schematic.query(&SymbolQuery {
+        symbol,
+        min_arity,
+        max_arity,
+        aggregate,
+    });
+
+    for (symbol, values, result) in results.iter() {
+        println!(
+            "{} at {},{}: {:?} -> {}",
+            symbol.char, symbol.row, symbol.col, values, result
+        );
+    }
+    let total: u64 = results.iter().map(|(_, _, r)| r).sum();
+    println!("Total: {total} over {} symbols", results.len());
+}
+
+// Main
+
+pub fn run() {
+    println!("Part 1: {}", part1());
+    println!("Part 2: {}", part2());
+}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(), 527446);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(), 73201705);
+    }
+
+    #[test]
+    fn test_schematic() {
+        let schematic = Schematic::parse("467..114..\n...*......\n..35..633.\n......#...\n");
+
+        assert_eq!(schematic.numbers.len(), 4);
+        assert_eq!(schematic.symbols.len(), 2);
+        let values: Vec<u32> = schematic.adjacent_numbers(0).map(|n| n.value).collect();
+        assert_eq!(values, [467, 35]);
+        assert_eq!(schematic.number_symbols[1], Vec::<usize>::new());
+        assert_eq!(schematic.number_symbols[3], [1]);
+    }
+
+    #[test]
+    fn test_query() {
+        let schematic = Schematic::parse("1.2.3\n.#.*.\n4.5.6\n");
+        let busy = SymbolQuery {
+            symbol: '#',
+            min_arity: 3,
+            max_arity: None,
+            aggregate: Aggregate::Count,
+        };
+        let results = schematic.query(&busy);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].1, [1, 2, 4, 5]);
+        assert_eq!(results[0].2, 4);
+        assert_eq!(parse_arity("2..4"), (2, Some(4)));
+        assert_eq!(parse_arity("..4"), (0, Some(4)));
+        assert_eq!(parse_arity("3"), (3, Some(3)));
+    }
+}
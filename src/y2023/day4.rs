@@ -0,0 +1,305 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::fs;
+use std::fs::File;
+use std::io::{self, BufRead};
+
+// Common
+
+#[derive(Debug)]
+struct Card {
+    id: u32,
+    winning_numbers: HashSet<u32>,
+    your_numbers: HashSet<u32>,
+}
+
+fn parse_card(input: &str) -> Card {
+    let (card, nums) = input.split_once(":").unwrap();
+    let id: u32 = card.split_whitespace().last().unwrap().parse().unwrap();
+    let (winning, yours) = nums.split_once("|").unwrap();
+
+    return Card {
+        id,
+        winning_numbers: winning
+            .trim()
+            .split_whitespace()
+            .map(|n| n.parse().unwrap())
+            .collect(),
+        your_numbers: yours
+            .trim()
+            .split_whitespace()
+            .map(|n| n.parse().unwrap())
+            .collect(),
+    };
+}
+
+fn parse_input() -> Vec<Card> {
+    let contents = fs::read_to_string("./inputs/2023/day4.txt").expect("No file found");
+    let lines = contents.split("\n");
+
+    return lines.filter(|l| l.len() > 0).map(parse_card).collect();
+}
+
+fn num_winning_cards(card: &Card) -> u32 {
+    let mut winning_cards = 0;
+    for your_num in card.your_numbers.iter() {
+        if card.winning_numbers.contains(your_num) {
+            winning_cards += 1;
+        }
+    }
+    return winning_cards;
+}
+
+// Part 1
+
+fn card_score(card: &Card) -> u32 {
+    let winning_cards = num_winning_cards(card);
+    if winning_cards > 0 {
+        return u32::pow(2, winning_cards - 1);
+    }
+    return 0;
+}
+
+fn part1() -> u32 {
+    let cards = parse_input();
+    return cards.iter().map(card_score).sum();
+}
+
+// Part 2
+
+/// How winning matches turn into copies of later cards.
+#[derive(Debug, Clone, Copy)]
+struct CopyRules {
+    // How far after the winning card the window of won cards starts
+    offset: usize,
+    // Drop copies that would land past the last card
+    cap_at_last: bool,
+    // Copies won of each card in the window
+    multiplier: u64,
+}
+
+const PUZZLE_RULES: CopyRules = CopyRules {
+    offset: 1,
+    cap_at_last: true,
+    multiplier: 1,
+};
+
+#[derive(Debug, PartialEq)]
+struct Cascade {
+    // Total instances of each card, originals included
+    copies: Vec<u64>,
+    // Copies won of cards past the end, when not capped
+    beyond_end: u64,
+}
+
+impl Cascade {
+    fn total(&self) -> u64 {
+        self.copies.iter().sum::<u64>() + self.beyond_end
+    }
+}
+
+fn cascade(cards: &[Card], rules: &CopyRules) -> Cascade {
+    let mut copies = vec![1; cards.len()];
+    let mut beyond = HashMap::new();
+
+    for (i, card) in cards.iter().enumerate() {
+        let instances = copies[i];
+        let start = i + rules.offset;
+        let end = start + num_winning_cards(card) as usize;
+
+        for won in start..end {
+            match copies.get_mut(won) {
+                Some(count) => *count += instances * rules.multiplier,
+                None if !rules.cap_at_last => {
+                    *beyond.entry(won).or_insert(0) += instances * rules.multiplier
+                }
+                None => (),
+            }
+        }
+    }
+
+    Cascade {
+        copies,
+        beyond_end: beyond.into_values().sum(),
+    }
+}
+
+fn part2() -> u64 {
+    let cards = parse_input();
+    cascade(&cards, &PUZZLE_RULES).total()
+}
+
+// Copies
+
+/// Prints how many instances of each card end up in the pile. Takes optional
+/// `OFFSET MULTIPLIER cap|nocap` rules, defaulting to the puzzle's.
+pub fn copies(args: &[String]) {
+    let rules = CopyRules {
+        offset: args
+            .first()
+            .map_or(PUZZLE_RULES.offset, |a| a.parse().expect("Invalid offset")),
+        multiplier: args.get(1).map_or(PUZZLE_RULES.multiplier, |a| {
+            a.parse().expect("Invalid multiplier")
+        }),
+        cap_at_last: args
+            .get(2)
+            .map_or(PUZZLE_RULES.cap_at_last, |a| a != "nocap"),
+    };
+    let cards = parse_input();
+    let result = cascade(&cards, &rules);
+
+    for (card, count) in cards.iter().zip(result.copies.iter()) {
+        println!("Card {}: {}", card.id, count);
+    }
+    if result.beyond_end > 0 {
+        println!("Past the last card: {}", result.beyond_end);
+    }
+    println!("Total: {}", result.total());
+}
+
+// Streaming
+
+#[derive(Debug, PartialEq)]
+struct StreamTotals {
+    cards: u64,
+    total: u128,
+    beyond_end: u128,
+}
+
+/// Runs the cascade over cards read one line at a time, holding only the
+/// pending copies for the cards still within reach of a win. `on_card` sees
+/// each card with its final instance count as soon as it is known.
+fn stream_cascade(
+    reader: impl BufRead,
+    rules: &CopyRules,
+    on_card: &mut dyn FnMut(&Card, u128),
+) -> StreamTotals {
+    // pending[k] holds copies already won of the card k places ahead
+    let mut pending: VecDeque<u128> = VecDeque::new();
+    let mut totals = StreamTotals {
+        cards: 0,
+        total: 0,
+        beyond_end: 0,
+    };
+
+    for line in reader.lines() {
+        let line = line.expect("lines failed");
+        if line.trim().is_empty() {
+            continue;
+        }
+        let card = parse_card(&line);
+        let instances = 1 + pending.pop_front().unwrap_or(0);
+        let reach = rules.offset + num_winning_cards(&card) as usize;
+        if pending.len() + 1 < reach {
+            pending.resize(reach - 1, 0);
+        }
+        for ahead in rules.offset.max(1)..reach {
+            pending[ahead - 1] += instances * rules.multiplier as u128;
+        }
+        // An offset of zero makes a card win copies of itself.
+        let self_copies = if rules.offset == 0 && reach > 0 {
+            instances * rules.multiplier as u128
+        } else {
+            0
+        };
+
+        on_card(&card, instances + self_copies);
+        totals.cards += 1;
+        totals.total += instances + self_copies;
+    }
+
+    if !rules.cap_at_last {
+        totals.beyond_end = pending.iter().sum();
+        totals.total += totals.beyond_end;
+    }
+
+    totals
+}
+
+/// Streams a (possibly huge) card file through the puzzle rules, printing only
+/// the totals. Defaults to the puzzle input.
+pub fn stream(args: &[String]) {
+    let path = args
+        .first()
+        .map_or("./inputs/2023/day4.txt", |a| a.as_str());
+    let file = File::open(path).expect("File not found");
+    let totals = stream_cascade(io::BufReader::new(file), &PUZZLE_RULES, &mut |_, _| {});
+
+    println!("Cards: {}", totals.cards);
+    println!("Total: {}", totals.total);
+}
+
+// Main
+
+pub fn run() {
+    println!("Part 1: {}", part1());
+    println!("Part 2: {}", part2());
+}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(), 22674);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(), 5747443);
+    }
+
+    #[test]
+    fn test_cascade_rules() {
+        let cards: Vec<Card> = ["Card 1: 1 2 | 1 2", "Card 2: 3 | 3", "Card 3: 4 | 5"]
+            .iter()
+            .map(|c| parse_card(c))
+            .collect();
+
+        let capped = cascade(&cards, &PUZZLE_RULES);
+        assert_eq!(capped.copies, [1, 2, 4]);
+        assert_eq!(capped.total(), 7);
+
+        let uncapped = CopyRules {
+            offset: 2,
+            cap_at_last: false,
+            multiplier: 3,
+        };
+        let result = cascade(&cards, &uncapped);
+        assert_eq!(result.copies, [1, 1, 4]);
+        assert_eq!(result.beyond_end, 3 + 3);
+    }
+
+    #[test]
+    fn test_stream_cascade() {
+        let file = File::open("./inputs/2023/day4.txt").unwrap();
+        let mut counts = Vec::new();
+        let totals = stream_cascade(io::BufReader::new(file), &PUZZLE_RULES, &mut |_, n| {
+            counts.push(n as u64)
+        });
+
+        let cards = parse_input();
+        assert_eq!(counts, cascade(&cards, &PUZZLE_RULES).copies);
+        assert_eq!(totals.total, 5747443);
+
+        let uncapped = CopyRules {
+            offset: 2,
+            cap_at_last: false,
+            multiplier: 3,
+        };
+        let pile = "Card 1: 1 2 | 1 2\nCard 2: 3 | 3\nCard 3: 4 | 5\n";
+        let totals = stream_cascade(io::Cursor::new(pile), &uncapped, &mut |_, _| {});
+        assert_eq!(
+            totals,
+            StreamTotals {
+                cards: 3,
+                total: 12,
+                beyond_end: 6,
+            }
+        );
+    }
+}
@@ -0,0 +1,608 @@
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, BufRead};
+
+use crate::common::interval::Interval;
+
+// Common
+
+#[derive(Debug)]
+struct AlmanacMapping {
+    source_range_start: u64,
+    destination_range_start: u64,
+    range_length: u64,
+}
+
+impl AlmanacMapping {
+    fn source(&self) -> Interval {
+        Interval::with_len(self.source_range_start as i64, self.range_length as i64)
+    }
+
+    fn offset(&self) -> i64 {
+        self.destination_range_start as i64 - self.source_range_start as i64
+    }
+}
+
+#[derive(Debug)]
+struct AlmanacSection {
+    input: String,
+    output: String,
+    mappings: Vec<AlmanacMapping>,
+}
+
+fn file_reader() -> io::BufReader<File> {
+    let file = File::open("./inputs/2023/day5.txt").unwrap();
+    return io::BufReader::new(file);
+}
+
+fn get_seeds(reader: &mut io::BufReader<File>) -> std::io::Result<Vec<u64>> {
+    let mut seed_buffer = String::new();
+    reader.read_line(&mut seed_buffer)?;
+    reader.read_line(&mut seed_buffer)?;
+    return Ok(seed_buffer
+        .split(": ")
+        .last()
+        .unwrap()
+        .split_whitespace()
+        .map(|s| s.parse().unwrap())
+        .collect());
+}
+
+fn get_almanac_entry(reader: &mut io::BufReader<File>) -> std::io::Result<(bool, AlmanacSection)> {
+    let mut section_title = String::new();
+    reader.read_line(&mut section_title)?;
+    let (input, output): (&str, &str) = section_title
+        .split(" ")
+        .next()
+        .unwrap()
+        .split_once("-to-")
+        .unwrap();
+
+    let mut mappings: Vec<AlmanacMapping> = Vec::new();
+    let mut eof = false;
+    loop {
+        let mut line = String::new();
+        let len = reader.read_line(&mut line).unwrap();
+        if len == 0 {
+            eof = true;
+            break;
+        }
+        if line == "\n" {
+            break;
+        }
+        let mut nums = line.trim().split(" ").map(|n| n.parse().unwrap());
+        mappings.push(AlmanacMapping {
+            destination_range_start: nums.next().unwrap(),
+            source_range_start: nums.next().unwrap(),
+            range_length: nums.next().unwrap(),
+        });
+    }
+
+    return Ok((
+        eof,
+        AlmanacSection {
+            input: input.to_string(),
+            output: output.to_string(),
+            mappings,
+        },
+    ));
+}
+
+fn parse_input() -> (Vec<u64>, Almanac) {
+    let mut sections: Vec<AlmanacSection> = Vec::new();
+
+    let mut reader = file_reader();
+    let seeds = get_seeds(&mut reader).unwrap();
+    loop {
+        let (eof, section) = get_almanac_entry(&mut reader).unwrap();
+        sections.push(section);
+        if eof {
+            break;
+        }
+    }
+
+    return (seeds, Almanac { sections });
+}
+
+// Composition
+
+// Everything the almanac can be asked about lies in 0..DOMAIN_END.
+const DOMAIN_END: i64 = i64::MAX / 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Piece {
+    source: Interval,
+    offset: i64,
+}
+
+/// A piecewise-linear map over the whole domain, as sorted, non-overlapping
+/// pieces that each shift their source by a fixed offset. Adjacent pieces
+/// with the same offset are merged, so equal functions compare equal.
+#[derive(Debug, Clone, PartialEq)]
+struct PiecewiseFunction {
+    pieces: Vec<Piece>,
+}
+
+impl PiecewiseFunction {
+    fn normalized(mut pieces: Vec<Piece>) -> PiecewiseFunction {
+        pieces.sort_by_key(|p| p.source.start);
+        let mut merged: Vec<Piece> = Vec::new();
+        for piece in pieces {
+            match merged.last_mut() {
+                Some(last)
+                    if last.source.end == piece.source.start && last.offset == piece.offset =>
+                {
+                    last.source.end = piece.source.end
+                }
+                _ => merged.push(piece),
+            }
+        }
+        PiecewiseFunction { pieces: merged }
+    }
+
+    /// The section as a function. Where mappings overlap the first one wins,
+    /// as it does when applying the section number by number.
+    fn from_section(section: &AlmanacSection) -> PiecewiseFunction {
+        let mut uncovered = vec![Interval::new(0, DOMAIN_END)];
+        let mut pieces = Vec::new();
+
+        for mapping in section.mappings.iter() {
+            let source = mapping.source();
+            let mut remaining = Vec::new();
+            for range in uncovered {
+                if let Some(overlap) = range.intersect(&source) {
+                    pieces.push(Piece {
+                        source: overlap,
+                        offset: mapping.offset(),
+                    });
+                }
+                remaining.extend(range.subtract(&source));
+            }
+            uncovered = remaining;
+        }
+
+        pieces.extend(
+            uncovered
+                .into_iter()
+                .map(|source| Piece { source, offset: 0 }),
+        );
+        PiecewiseFunction::normalized(pieces)
+    }
+
+    /// `next` applied after `self`.
+    fn then(&self, next: &PiecewiseFunction) -> PiecewiseFunction {
+        let mut pieces = Vec::new();
+
+        for piece in self.pieces.iter() {
+            let image = piece.source.shift(piece.offset);
+            for other in next.pieces.iter() {
+                if let Some(overlap) = image.intersect(&other.source) {
+                    pieces.push(Piece {
+                        source: overlap.shift(-piece.offset),
+                        offset: piece.offset + other.offset,
+                    });
+                }
+            }
+        }
+
+        PiecewiseFunction::normalized(pieces)
+    }
+
+    fn forward(&self, value: i64) -> i64 {
+        let index = self.pieces.partition_point(|p| p.source.end <= value);
+        match self.pieces.get(index) {
+            Some(piece) if piece.source.contains(value) => value + piece.offset,
+            _ => value,
+        }
+    }
+
+    /// Every value that maps onto `value`, in increasing order.
+    fn inverse(&self, value: i64) -> Vec<i64> {
+        let mut sources: Vec<i64> = self
+            .pieces
+            .iter()
+            .filter(|p| p.source.shift(p.offset).contains(value))
+            .map(|p| value - p.offset)
+            .collect();
+        sources.sort();
+        sources
+    }
+
+    /// The inverse function, if every value has exactly one source.
+    fn invert(&self) -> Option<PiecewiseFunction> {
+        let mut pieces: Vec<Piece> = self
+            .pieces
+            .iter()
+            .map(|p| Piece {
+                source: p.source.shift(p.offset),
+                offset: -p.offset,
+            })
+            .collect();
+        pieces.sort_by_key(|p| p.source.start);
+
+        let mut expected_start = 0;
+        for piece in pieces.iter() {
+            if piece.source.start != expected_start {
+                return None;
+            }
+            expected_start = piece.source.end;
+        }
+        if expected_start != DOMAIN_END {
+            return None;
+        }
+
+        Some(PiecewiseFunction::normalized(pieces))
+    }
+}
+
+// Category graph
+
+/// The almanac as a graph of categories, where each section is an edge from
+/// its input category to its output category. Sections can also be walked
+/// backwards when they map one to one.
+#[derive(Debug)]
+struct Almanac {
+    sections: Vec<AlmanacSection>,
+}
+
+impl Almanac {
+    /// The shortest chain of sections from one category to another, with
+    /// `true` for sections walked forwards and `false` for backwards.
+    fn path(&self, from: &str, to: &str) -> Option<Vec<(usize, bool)>> {
+        let mut previous: HashMap<&str, (&str, usize, bool)> = HashMap::new();
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(category) = queue.pop_front() {
+            if category == to {
+                let mut steps = Vec::new();
+                let mut current = to;
+                while current != from {
+                    let (before, section, forward) = previous[current];
+                    steps.push((section, forward));
+                    current = before;
+                }
+                steps.reverse();
+                return Some(steps);
+            }
+
+            for (index, section) in self.sections.iter().enumerate() {
+                let (next, forward) = if section.input == category {
+                    (section.output.as_str(), true)
+                } else if section.output == category {
+                    (section.input.as_str(), false)
+                } else {
+                    continue;
+                };
+                if next != from && !previous.contains_key(next) {
+                    previous.insert(next, (category, index, forward));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// The function taking values in one category to another, or `None` when
+    /// the categories don't connect or a backwards step isn't one to one.
+    fn mapping(&self, from: &str, to: &str) -> Option<PiecewiseFunction> {
+        let identity = PiecewiseFunction::normalized(vec![Piece {
+            source: Interval::new(0, DOMAIN_END),
+            offset: 0,
+        }]);
+
+        self.path(from, to)?
+            .into_iter()
+            .try_fold(identity, |composed, (index, forward)| {
+                let step = PiecewiseFunction::from_section(&self.sections[index]);
+                let step = if forward { step } else { step.invert()? };
+                Some(composed.then(&step))
+            })
+    }
+}
+
+// Validation
+
+#[derive(Debug, PartialEq)]
+enum AlmanacIssue {
+    UnsortedMappings {
+        section: String,
+        index: usize,
+    },
+    OverlappingMappings {
+        section: String,
+        first: usize,
+        second: usize,
+    },
+    BrokenChain {
+        output: String,
+        next_input: String,
+    },
+}
+
+fn section_name(section: &AlmanacSection) -> String {
+    format!("{}-to-{}", section.input, section.output)
+}
+
+fn validate(almanac: &[AlmanacSection]) -> Vec<AlmanacIssue> {
+    let mut issues = Vec::new();
+
+    for section in almanac.iter() {
+        for (index, pair) in section.mappings.windows(2).enumerate() {
+            if pair[1].source_range_start < pair[0].source_range_start {
+                issues.push(AlmanacIssue::UnsortedMappings {
+                    section: section_name(section),
+                    index: index + 1,
+                });
+            }
+        }
+        for (first, a) in section.mappings.iter().enumerate() {
+            for (second, b) in section.mappings.iter().enumerate().skip(first + 1) {
+                if a.source().intersect(&b.source()).is_some() {
+                    issues.push(AlmanacIssue::OverlappingMappings {
+                        section: section_name(section),
+                        first,
+                        second,
+                    });
+                }
+            }
+        }
+    }
+
+    for pair in almanac.windows(2) {
+        if pair[0].output != pair[1].input {
+            issues.push(AlmanacIssue::BrokenChain {
+                output: pair[0].output.to_string(),
+                next_input: pair[1].input.to_string(),
+            });
+        }
+    }
+
+    issues
+}
+
+/// Prints any problems found in the puzzle almanac.
+pub fn validate_almanac(_args: &[String]) {
+    let (_, almanac) = parse_input();
+    let issues = validate(&almanac.sections);
+
+    for issue in issues.iter() {
+        match issue {
+            AlmanacIssue::UnsortedMappings { section, index } => {
+                println!("{section}: mapping {index} starts before the one above it")
+            }
+            AlmanacIssue::OverlappingMappings {
+                section,
+                first,
+                second,
+            } => println!("{section}: mappings {first} and {second} overlap"),
+            AlmanacIssue::BrokenChain { output, next_input } => {
+                println!("{output} is followed by a section starting from {next_input}")
+            }
+        }
+    }
+    println!("{} issues found", issues.len());
+}
+
+/// Looks up where a value in one category ends up in another, seed to
+/// location by default. With `inverse` it lists the values that map onto it
+/// instead.
+pub fn lookup(args: &[String]) {
+    let usage = "Usage: lookup VALUE [FROM TO] [inverse]";
+    let value: i64 = args.first().expect(usage).parse().expect("Invalid value");
+    let inverse = args.last().is_some_and(|a| a == "inverse");
+    let categories: Vec<&str> = args[1..]
+        .iter()
+        .map(|a| a.as_str())
+        .filter(|a| *a != "inverse")
+        .collect();
+    let (from, to) = match categories[..] {
+        [] => ("seed", "location"),
+        [from, to] => (from, to),
+        _ => panic!("{usage}"),
+    };
+
+    let (_, almanac) = parse_input();
+    let Some(function) = almanac.mapping(from, to) else {
+        println!("No one to one mapping from {from} to {to}");
+        return;
+    };
+
+    if inverse {
+        println!("{:?}", function.inverse(value));
+    } else {
+        println!("{}", function.forward(value));
+    }
+}
+
+// Part 1
+
+fn part1() -> u64 {
+    let (seeds, almanac) = parse_input();
+    let seed_to_location = almanac.mapping("seed", "location").unwrap();
+
+    seeds
+        .iter()
+        .map(|seed| seed_to_location.forward(*seed as i64))
+        .min()
+        .unwrap() as u64
+}
+
+// Part 2
+
+/// Pushes whole ranges through a section, splitting them wherever they cross
+/// a mapping boundary. Anything no mapping covers passes through unchanged.
+fn map_ranges_to_section(ranges: Vec<Interval>, section: &AlmanacSection) -> Vec<Interval> {
+    let mut unmapped = ranges;
+    let mut mapped = Vec::new();
+
+    for mapping in section.mappings.iter() {
+        let source = mapping.source();
+        let mut remaining = Vec::new();
+        for range in unmapped {
+            match range.intersect(&source) {
+                Some(overlap) => {
+                    mapped.push(overlap.shift(mapping.offset()));
+                    remaining.extend(range.subtract(&source));
+                }
+                None => remaining.push(range),
+            }
+        }
+        unmapped = remaining;
+    }
+
+    mapped.extend(unmapped);
+    mapped
+}
+
+fn seed_ranges(seeds: &[u64]) -> Vec<Interval> {
+    seeds
+        .chunks(2)
+        .map(|chunk| Interval::with_len(chunk[0] as i64, chunk[1] as i64))
+        .collect()
+}
+
+fn part2() -> u64 {
+    let (seeds, almanac) = parse_input();
+
+    let path = almanac.path("seed", "location").unwrap();
+    assert!(path.iter().all(|(_, forward)| *forward));
+
+    let locations = path.iter().fold(seed_ranges(&seeds), |ranges, (index, _)| {
+        map_ranges_to_section(ranges, &almanac.sections[*index])
+    });
+
+    locations.iter().map(|r| r.start).min().unwrap() as u64
+}
+
+// Main
+
+pub fn run() {
+    println!("Part 1: {}", part1());
+    println!("Part 2: {}", part2());
+}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(), 174137457);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(), 1493866);
+    }
+
+    #[test]
+    fn test_map_ranges_to_section() {
+        let section = AlmanacSection {
+            input: "seed".to_string(),
+            output: "soil".to_string(),
+            mappings: vec![
+                AlmanacMapping {
+                    destination_range_start: 52,
+                    source_range_start: 50,
+                    range_length: 48,
+                },
+                AlmanacMapping {
+                    destination_range_start: 50,
+                    source_range_start: 98,
+                    range_length: 2,
+                },
+            ],
+        };
+        let mut mapped = map_ranges_to_section(vec![Interval::new(40, 100)], &section);
+        mapped.sort();
+
+        assert_eq!(
+            mapped,
+            [
+                Interval::new(40, 50),
+                Interval::new(50, 52),
+                Interval::new(52, 100),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compose() {
+        let (seeds, almanac) = parse_input();
+        let composed = almanac.mapping("seed", "location").unwrap();
+
+        for seed in seeds.iter().map(|s| *s as i64) {
+            let location = almanac.sections.iter().fold(seed, |n, section| {
+                map_ranges_to_section(vec![Interval::new(n, n + 1)], section)[0].start
+            });
+            assert_eq!(composed.forward(seed), location);
+            assert!(composed.inverse(location).contains(&seed));
+        }
+        assert!(composed
+            .pieces
+            .windows(2)
+            .all(|p| p[0].source.end == p[1].source.start && p[0].offset != p[1].offset));
+    }
+
+    #[test]
+    fn test_validate() {
+        let mapping = |source_range_start, range_length| AlmanacMapping {
+            source_range_start,
+            destination_range_start: 0,
+            range_length,
+        };
+        let almanac = vec![
+            AlmanacSection {
+                input: "seed".to_string(),
+                output: "soil".to_string(),
+                mappings: vec![mapping(10, 5), mapping(0, 12)],
+            },
+            AlmanacSection {
+                input: "water".to_string(),
+                output: "light".to_string(),
+                mappings: vec![],
+            },
+        ];
+
+        assert_eq!(
+            validate(&almanac),
+            [
+                AlmanacIssue::UnsortedMappings {
+                    section: "seed-to-soil".to_string(),
+                    index: 1,
+                },
+                AlmanacIssue::OverlappingMappings {
+                    section: "seed-to-soil".to_string(),
+                    first: 0,
+                    second: 1,
+                },
+                AlmanacIssue::BrokenChain {
+                    output: "soil".to_string(),
+                    next_input: "water".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_category_mapping() {
+        let (_, almanac) = parse_input();
+        let light_to_humidity = almanac.mapping("light", "humidity").unwrap();
+        let humidity_to_light = almanac.mapping("humidity", "light").unwrap();
+
+        assert_eq!(
+            almanac.path("light", "humidity"),
+            Some(vec![(4, true), (5, true)])
+        );
+        assert_eq!(almanac.path("humidity", "soil").unwrap().len(), 5);
+        for value in [0, 79, 1132132257, 3602765034] {
+            let humidity = light_to_humidity.forward(value);
+            assert_eq!(humidity_to_light.forward(humidity), value);
+        }
+        assert!(almanac.mapping("seed", "nowhere").is_none());
+    }
+}
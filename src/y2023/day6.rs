@@ -0,0 +1,442 @@
+use std::fs;
+
+// Common
+
+#[derive(Debug, PartialEq)]
+struct Race {
+    time: u128,
+    distance: u128,
+}
+
+fn parse_line<'a>(line: &'a str, label: &str) -> Vec<&'a str> {
+    line.strip_prefix(label)
+        .expect("Unexpected line")
+        .split_whitespace()
+        .collect()
+}
+
+/// Reads the races column by column, along with the single race you get by
+/// ignoring the spaces between the numbers.
+fn parse_races(input: &str) -> (Vec<Race>, Race) {
+    let mut lines = input.lines().filter(|l| !l.is_empty());
+    let times = parse_line(lines.next().expect("No times"), "Time:");
+    let distances = parse_line(lines.next().expect("No distances"), "Distance:");
+    let parse = |n: &str| -> u128 { n.parse().expect("Invalid number") };
+
+    let races = times
+        .iter()
+        .zip(distances.iter())
+        .map(|(time, distance)| Race {
+            time: parse(time),
+            distance: parse(distance),
+        })
+        .collect();
+    let joined = Race {
+        time: parse(&times.concat()),
+        distance: parse(&distances.concat()),
+    };
+
+    (races, joined)
+}
+
+fn parse_input() -> (Vec<Race>, Race) {
+    let contents = fs::read_to_string("./inputs/2023/day6.txt").expect("No file found");
+    parse_races(&contents)
+}
+
+/// Whether `hold` beats the record. A product too large for u128 beats any
+/// u128 distance, so overflow counts as a win.
+fn wins(race: &Race, hold: u128) -> bool {
+    hold.checked_mul(race.time - hold)
+        .is_none_or(|distance| distance > race.distance)
+}
+
+/// The first winning hold time at or below `half`, found by bisection.
+fn first_win_by_bisection(race: &Race, half: u128) -> u128 {
+    let (mut low, mut high) = (0, half);
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if wins(race, middle) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+    low
+}
+
+/// The range of winning hold times, from the roots of
+/// `t * (time - t) = distance`. With `time = 2 * half + r`, the discriminant
+/// is `4 * (half * (half + r) - distance) + r`, and its integer square root
+/// puts the first root within one step of the exact boundary. When that
+/// overflows, the boundary is bisected instead.
+fn winning_holds(race: &Race) -> Option<(u128, u128)> {
+    let half = race.time / 2;
+    if !wins(race, half) {
+        return None;
+    }
+
+    let discriminant = half
+        .checked_mul(race.time - half)
+        .and_then(|best| (best - race.distance).checked_mul(4))
+        .and_then(|d| d.checked_add(race.time % 2));
+    let mut first = match discriminant {
+        Some(discriminant) => (race.time - discriminant.isqrt()) / 2,
+        None => first_win_by_bisection(race, half),
+    };
+    while first > 0 && wins(race, first - 1) {
+        first -= 1;
+    }
+    while !wins(race, first) {
+        first += 1;
+    }
+
+    // Winning hold times are symmetric around time / 2.
+    Some((first, race.time - first))
+}
+
+fn race_possibilities(race: &Race) -> u128 {
+    winning_holds(race).map_or(0, |(first, last)| last - first + 1)
+}
+
+// Part 1
+
+fn part1() -> u128 {
+    let (races, _) = parse_input();
+    races.iter().map(race_possibilities).product()
+}
+
+// Part 2
+
+fn part2() -> u128 {
+    let (_, race) = parse_input();
+    race_possibilities(&race)
+}
+
+// Strategy
+
+/// How a boat turns holding the button into distance. Each millisecond held
+/// adds `charge_rate` to the speed, up to `max_speed`, and each millisecond
+/// moving then takes `drag` off it again.
+#[derive(Debug, Clone, Copy)]
+struct RaceModel {
+    charge_rate: u128,
+    max_speed: Option<u128>,
+    drag: u128,
+}
+
+const PUZZLE_MODEL: RaceModel = RaceModel {
+    charge_rate: 1,
+    max_speed: None,
+    drag: 0,
+};
+
+#[derive(Debug, PartialEq)]
+struct Strategy {
+    // Inclusive ranges of winning hold times
+    winning: Vec<(u128, u128)>,
+    best_hold: u128,
+    best_distance: u128,
+}
+
+impl RaceModel {
+    fn speed(&self, hold: u128) -> u128 {
+        let speed = self.charge_rate.saturating_mul(hold);
+        self.max_speed.map_or(speed, |max| speed.min(max))
+    }
+
+    /// The distance travelled, saturating at `u128::MAX`, which is still
+    /// enough to compare against any u128 record.
+    fn distance(&self, race: &Race, hold: u128) -> u128 {
+        let speed = self.speed(hold);
+        let moving = race.time - hold;
+        if self.drag == 0 {
+            return speed.saturating_mul(moving);
+        }
+
+        // speed, speed - drag, ... for as long as it stays positive, summed as
+        // steps * (first + last) / 2 with whichever factor is even halved
+        let steps = moving.min(speed.div_ceil(self.drag));
+        if steps == 0 {
+            return 0;
+        }
+        let last = speed - self.drag * (steps - 1);
+        let Some(ends) = speed.checked_add(last) else {
+            return u128::MAX;
+        };
+        let total = if steps.is_multiple_of(2) {
+            (steps / 2).checked_mul(ends)
+        } else {
+            steps.checked_mul(ends / 2)
+        };
+        total.unwrap_or(u128::MAX)
+    }
+
+    /// The last hold time before the speed cap is reached, if there is one.
+    fn last_uncapped_hold(&self, race: &Race) -> u128 {
+        match self.max_speed {
+            Some(max) if self.charge_rate > 0 => (max / self.charge_rate).min(race.time),
+            _ => race.time,
+        }
+    }
+
+    /// Solves the race in closed form when there is no drag, and otherwise by
+    /// trying every hold time.
+    fn explore(&self, race: &Race) -> Strategy {
+        if self.drag > 0 || self.charge_rate == 0 {
+            return self.explore_by_scanning(race);
+        }
+
+        let cap = self.last_uncapped_hold(race);
+        let mut winning = Vec::new();
+
+        // Uncapped, r * t * (time - t) > distance is t * (time - t) > distance / r.
+        let scaled = Race {
+            time: race.time,
+            distance: race.distance / self.charge_rate,
+        };
+        if let Some((first, last)) = winning_holds(&scaled) {
+            if first <= cap {
+                winning.push((first, last.min(cap)));
+            }
+        }
+
+        // Capped, max * (time - t) > distance.
+        if let Some(max) = self.max_speed.filter(|m| *m > 0) {
+            let last = race.time.checked_sub(race.distance / max + 1);
+            if let Some(last) = last.filter(|l| *l > cap) {
+                match winning.last_mut() {
+                    Some(previous) if previous.1 == cap => previous.1 = last,
+                    _ => winning.push((cap + 1, last)),
+                }
+            }
+        }
+
+        let middle = race.time / 2;
+        let candidates = [
+            middle.min(cap),
+            (middle + 1).min(cap),
+            (cap + 1).min(race.time),
+        ];
+        let best_hold = candidates
+            .into_iter()
+            .max_by_key(|h| (self.distance(race, *h), u128::MAX - h))
+            .unwrap();
+
+        Strategy {
+            winning,
+            best_hold,
+            best_distance: self.distance(race, best_hold),
+        }
+    }
+
+    fn explore_by_scanning(&self, race: &Race) -> Strategy {
+        let mut winning: Vec<(u128, u128)> = Vec::new();
+        let mut best_hold = 0;
+        let mut best_distance = 0;
+
+        for hold in 0..=race.time {
+            let distance = self.distance(race, hold);
+            if distance > best_distance {
+                best_hold = hold;
+                best_distance = distance;
+            }
+            if distance > race.distance {
+                match winning.last_mut() {
+                    Some(previous) if previous.1 + 1 == hold => previous.1 = hold,
+                    _ => winning.push((hold, hold)),
+                }
+            }
+        }
+
+        Strategy {
+            winning,
+            best_hold,
+            best_distance,
+        }
+    }
+}
+
+/// Prints the winning hold times and the best hold for each race under a
+/// custom model: `RATE [MAX_SPEED|none] [DRAG]`.
+pub fn strategy(args: &[String]) {
+    let model = RaceModel {
+        charge_rate: args.first().map_or(PUZZLE_MODEL.charge_rate, |a| {
+            a.parse().expect("Invalid rate")
+        }),
+        max_speed: args
+            .get(1)
+            .filter(|a| *a != "none")
+            .map(|a| a.parse().expect("Invalid max speed")),
+        drag: args
+            .get(2)
+            .map_or(PUZZLE_MODEL.drag, |a| a.parse().expect("Invalid drag")),
+    };
+    let (races, joined) = parse_input();
+
+    for race in races.iter().chain([&joined]) {
+        let strategy = model.explore(race);
+        let winning: Vec<String> = strategy
+            .winning
+            .iter()
+            .map(|(first, last)| format!("{first}..={last}"))
+            .collect();
+        println!(
+            "Race {}ms / {}mm: win with {}, best hold {}ms for {}mm",
+            race.time,
+            race.distance,
+            if winning.is_empty() {
+                "nothing".to_string()
+            } else {
+                winning.join(", ")
+            },
+            strategy.best_hold,
+            strategy.best_distance
+        );
+    }
+}
+
+// Main
+
+pub fn run() {
+    println!("Part 1: {}", part1());
+    println!("Part 2: {}", part2());
+}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(), 114400);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(), 21039729);
+    }
+
+    #[test]
+    fn test_race_possibilities() {
+        let (races, joined) = parse_races("Time:      7  15   30\nDistance:  9  40  200\n");
+
+        assert_eq!(
+            joined,
+            Race {
+                time: 71530,
+                distance: 940200
+            }
+        );
+        let counts: Vec<u128> = races.iter().map(race_possibilities).collect();
+        assert_eq!(counts, [4, 8, 9]);
+        assert_eq!(race_possibilities(&joined), 71503);
+        assert_eq!(
+            race_possibilities(&Race {
+                time: 4,
+                distance: 4
+            }),
+            0
+        );
+        assert_eq!(
+            race_possibilities(&Race {
+                time: 4,
+                distance: 3
+            }),
+            1
+        );
+
+        let huge = Race {
+            time: 1 << 60,
+            distance: (1 << 118) - 1,
+        };
+        assert_eq!(race_possibilities(&huge), 1);
+
+        // Times past 2^64, where time * time no longer fits in a u128
+        let past_u64 = Race {
+            time: (1 << 64) + 6,
+            distance: 1 << 126,
+        };
+        // (2^63 + 3 - k) * (2^63 + 3 + k) > 2^126 exactly when k^2 < 6 * 2^63 + 9
+        let k = ((6u128 << 63) + 9 - 1).isqrt();
+        assert_eq!(race_possibilities(&past_u64), 2 * k + 1);
+
+        // An odd time with the record far below the best distance
+        let half: u128 = 1 << 63;
+        let odd = Race {
+            time: 2 * half + 1,
+            distance: half * (half + 1) - (1 << 120),
+        };
+        // (half - j) * (half + 1 + j) > distance exactly when j * (j + 1) < 2^120
+        let j = (1u128 << 60) - 1;
+        assert_eq!(winning_holds(&odd), Some((half - j, half + 1 + j)));
+
+        let longest = Race {
+            time: u128::MAX,
+            distance: u128::MAX - 2,
+        };
+        assert_eq!(winning_holds(&longest), Some((1, u128::MAX - 1)));
+        assert_eq!(
+            race_possibilities(&Race {
+                time: u128::MAX,
+                distance: u128::MAX,
+            }),
+            u128::MAX - 3
+        );
+    }
+
+    #[test]
+    fn test_race_models() {
+        let race = Race {
+            time: 30,
+            distance: 200,
+        };
+        let puzzle = PUZZLE_MODEL.explore(&race);
+        assert_eq!(puzzle.winning, [(11, 19)]);
+        assert_eq!((puzzle.best_hold, puzzle.best_distance), (15, 225));
+
+        let capped = RaceModel {
+            charge_rate: 2,
+            max_speed: Some(20),
+            drag: 0,
+        };
+        let closed_form = capped.explore(&race);
+        assert_eq!(closed_form, capped.explore_by_scanning(&race));
+        assert_eq!(closed_form.winning, [(4, 19)]);
+        assert_eq!(
+            (closed_form.best_hold, closed_form.best_distance),
+            (10, 400)
+        );
+
+        let dragged = RaceModel {
+            charge_rate: 3,
+            max_speed: None,
+            drag: 4,
+        };
+        assert_eq!(dragged.distance(&race, 4), 12 + 8 + 4);
+        let strategy = dragged.explore(&race);
+        assert_eq!((strategy.best_hold, strategy.best_distance), (21, 423));
+
+        // Huge races saturate instead of overflowing
+        let huge = Race {
+            time: 1 << 100,
+            distance: 5,
+        };
+        let strategy = PUZZLE_MODEL.explore(&huge);
+        assert_eq!(strategy.winning, [(1, (1 << 100) - 1)]);
+        assert_eq!(
+            (strategy.best_hold, strategy.best_distance),
+            (1 << 99, u128::MAX)
+        );
+        let unit_drag = RaceModel {
+            charge_rate: 1,
+            max_speed: None,
+            drag: 1,
+        };
+        // 2^64 + (2^64 - 1) + ... + 1 fits even though 2^64 * 2^64 does not
+        assert_eq!(unit_drag.distance(&huge, 1 << 64), (1 << 127) + (1 << 63));
+        assert_eq!(unit_drag.distance(&huge, 1 << 70), u128::MAX);
+    }
+}
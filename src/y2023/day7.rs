@@ -0,0 +1,350 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufRead};
+
+use itertools::Itertools;
+
+// Common
+
+/// A hand's category, as its card counts largest first. Comparing the counts
+/// in order ranks hands of any size, e.g. five of a kind `[5]` beats four of a
+/// kind `[4, 1]`, which beats a full house `[3, 2]`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct HandType {
+    counts: Vec<usize>,
+}
+
+fn number_word(n: usize) -> String {
+    const WORDS: [&str; 13] = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+        "eleven", "twelve",
+    ];
+    WORDS.get(n).map_or(n.to_string(), |w| w.to_string())
+}
+
+impl HandType {
+    fn from_counts(counts: &[usize]) -> HandType {
+        let mut counts = counts.to_vec();
+        counts.sort_by(|a, b| b.cmp(a));
+        HandType { counts }
+    }
+
+    fn name(&self) -> String {
+        let groups: Vec<usize> = self.counts.iter().copied().filter(|c| *c > 1).collect();
+        match groups[..] {
+            [] => return "High card".to_string(),
+            [3, 2] => return "Full house".to_string(),
+            _ => (),
+        }
+
+        let mut phrases = Vec::new();
+        for size in groups.iter().copied().dedup() {
+            let times = groups.iter().filter(|g| **g == size).count();
+            phrases.push(match (size, times) {
+                (2, 1) => "one pair".to_string(),
+                (2, 2) => "two pair".to_string(),
+                (2, _) => format!("{} pairs", number_word(times)),
+                (3, 2..) => format!("{} triples", number_word(times)),
+                (_, 1) => format!("{} of a kind", number_word(size)),
+                (_, _) => format!("{} {}s of a kind", number_word(times), number_word(size)),
+            });
+        }
+
+        let name = phrases.join(" and ");
+        name[..1].to_uppercase() + &name[1..]
+    }
+}
+
+/// The rules a game of camel cards is played by.
+#[derive(Debug)]
+struct Ruleset {
+    // Weakest card first
+    card_order: Vec<char>,
+    wildcards: Vec<char>,
+    hand_size: usize,
+}
+
+impl Ruleset {
+    fn standard() -> Ruleset {
+        Ruleset {
+            card_order: "23456789TJQKA".chars().collect(),
+            wildcards: Vec::new(),
+            hand_size: 5,
+        }
+    }
+
+    fn jokers() -> Ruleset {
+        Ruleset {
+            card_order: "J23456789TQKA".chars().collect(),
+            wildcards: vec!['J'],
+            hand_size: 5,
+        }
+    }
+
+    fn card_rank(&self, card: char) -> usize {
+        self.card_order
+            .iter()
+            .position(|c| *c == card)
+            .unwrap_or_else(|| panic!("Unknown card {card}"))
+    }
+
+    /// The best type the hand can make. Every wildcard joins the largest
+    /// group of natural cards, which is never worse than splitting them up.
+    fn classify(&self, hand: &str) -> HandType {
+        let mut counts: HashMap<char, usize> = HashMap::new();
+        let mut wildcards = 0;
+        for card in hand.chars() {
+            if self.wildcards.contains(&card) {
+                wildcards += 1;
+            } else {
+                *counts.entry(card).or_insert(0) += 1;
+            }
+        }
+
+        let mut counts: Vec<usize> = counts.into_values().collect();
+        counts.sort_by(|a, b| b.cmp(a));
+        match counts.first_mut() {
+            Some(largest) => *largest += wildcards,
+            None => counts.push(wildcards),
+        }
+
+        HandType::from_counts(&counts)
+    }
+
+    /// The card the hand's wildcards stand in for when classified: its most
+    /// common natural card, the strongest one on ties, or the strongest card
+    /// overall if every card is wild. `None` if the hand has no wildcards.
+    fn substitute(&self, hand: &str) -> Option<char> {
+        if !hand.chars().any(|c| self.wildcards.contains(&c)) {
+            return None;
+        }
+
+        let natural = hand.chars().filter(|c| !self.wildcards.contains(c));
+        let best = natural
+            .counts()
+            .into_iter()
+            .max_by_key(|(card, count)| (*count, self.card_rank(*card)));
+        best.map(|(card, _)| card).or_else(|| {
+            self.card_order
+                .iter()
+                .rev()
+                .find(|c| !self.wildcards.contains(c))
+                .copied()
+        })
+    }
+}
+
+#[derive(Debug)]
+struct CardHand {
+    hand: String,
+    hand_type: HandType,
+    bid: u32,
+}
+
+fn file_reader() -> io::BufReader<File> {
+    let file = File::open("./inputs/2023/day7.txt").unwrap();
+    io::BufReader::new(file)
+}
+
+fn parse_line(line: &str, ruleset: &Ruleset) -> CardHand {
+    let (hand, bid) = line.split_once(' ').unwrap();
+    assert_eq!(
+        hand.chars().count(),
+        ruleset.hand_size,
+        "Wrong hand size: {hand}"
+    );
+
+    CardHand {
+        hand: hand.to_string(),
+        hand_type: ruleset.classify(hand),
+        bid: bid.trim().parse().unwrap(),
+    }
+}
+
+fn get_sorted_hands(ruleset: &Ruleset) -> Vec<CardHand> {
+    let mut hands: Vec<CardHand> = file_reader()
+        .lines()
+        .map(|l| l.expect("lines failed"))
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| parse_line(&l, ruleset))
+        .collect();
+
+    hands.sort_by_cached_key(|hand| {
+        let ranks: Vec<usize> = hand.hand.chars().map(|c| ruleset.card_rank(c)).collect();
+        (hand.hand_type.clone(), ranks)
+    });
+
+    hands
+}
+
+fn get_winnings(ruleset: &Ruleset) -> u32 {
+    get_sorted_hands(ruleset)
+        .iter()
+        .enumerate()
+        .map(|(idx, hand)| hand.bid * (idx as u32 + 1))
+        .sum()
+}
+
+// Part 1
+
+fn part1() -> u32 {
+    get_winnings(&Ruleset::standard())
+}
+
+// Part 2
+
+fn part2() -> u32 {
+    get_winnings(&Ruleset::jokers())
+}
+
+// Report
+
+/// One line of the ranking report: a hand, how it was scored and what it won.
+#[derive(Debug)]
+struct ReportRow {
+    rank: usize,
+    hand: String,
+    hand_type: String,
+    substitute: Option<char>,
+    bid: u32,
+    winnings: u32,
+}
+
+fn report_rows(ruleset: &Ruleset) -> Vec<ReportRow> {
+    get_sorted_hands(ruleset)
+        .into_iter()
+        .enumerate()
+        .map(|(idx, hand)| ReportRow {
+            rank: idx + 1,
+            substitute: ruleset.substitute(&hand.hand),
+            hand_type: hand.hand_type.name(),
+            winnings: hand.bid * (idx as u32 + 1),
+            hand: hand.hand,
+            bid: hand.bid,
+        })
+        .collect()
+}
+
+/// Lists every hand weakest first with its type, what its jokers became, its
+/// rank, bid and winnings. Uses the part 2 rules unless given `standard`, and
+/// prints CSV instead of a table when given `csv`.
+pub fn report(args: &[String]) {
+    let ruleset = if args.iter().any(|a| a == "standard") {
+        Ruleset::standard()
+    } else {
+        Ruleset::jokers()
+    };
+    let rows = report_rows(&ruleset);
+    let joker = |row: &ReportRow| row.substitute.map_or("-".to_string(), |c| c.to_string());
+
+    if args.iter().any(|a| a == "csv") {
+        println!("rank,hand,type,joker,bid,winnings");
+        for row in rows.iter() {
+            println!(
+                "{},{},{},{},{},{}",
+                row.rank,
+                row.hand,
+                row.hand_type,
+                joker(row),
+                row.bid,
+                row.winnings
+            );
+        }
+        return;
+    }
+
+    let type_width = rows.iter().map(|r| r.hand_type.len()).max().unwrap_or(0);
+    let hand_width = ruleset.hand_size.max(4);
+    println!(
+        "{:>5}  {:<hand_width$}  {:<type_width$}  {:>5}  {:>5}  {:>9}",
+        "Rank", "Hand", "Type", "Joker", "Bid", "Winnings"
+    );
+    for row in rows.iter() {
+        println!(
+            "{:>5}  {:<hand_width$}  {:<type_width$}  {:>5}  {:>5}  {:>9}",
+            row.rank,
+            row.hand,
+            row.hand_type,
+            joker(row),
+            row.bid,
+            row.winnings
+        );
+    }
+    println!(
+        "Total winnings: {}",
+        rows.iter().map(|r| r.winnings).sum::<u32>()
+    );
+}
+
+// Main
+
+pub fn run() {
+    println!("Part 1: {}", part1());
+    println!("Part 2: {}", part2());
+}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(), 250946742);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(), 251824095);
+    }
+
+    #[test]
+    fn test_classify() {
+        let jokers = Ruleset::jokers();
+        let name = |hand| jokers.classify(hand).name();
+        assert_eq!(name("JJJJJ"), "Five of a kind");
+        assert_eq!(name("KTJJT"), "Four of a kind");
+        assert_eq!(name("2345J"), "One pair");
+        assert_eq!(name("22J33"), "Full house");
+        assert_eq!(Ruleset::standard().classify("22J33").name(), "Two pair");
+        assert_eq!(Ruleset::standard().classify("23456").name(), "High card");
+
+        let wild = Ruleset {
+            card_order: "23456789TJQKA".chars().collect(),
+            wildcards: vec!['2', '3'],
+            hand_size: 5,
+        };
+        assert_eq!(wild.classify("23A4K").name(), "Three of a kind");
+    }
+
+    #[test]
+    fn test_larger_hands() {
+        let six = Ruleset {
+            hand_size: 6,
+            ..Ruleset::jokers()
+        };
+        assert_eq!(six.classify("AAAJAA").name(), "Six of a kind");
+        assert_eq!(six.classify("KKKQQQ").name(), "Two triples");
+        assert_eq!(six.classify("KKQQ22").name(), "Three pairs");
+        assert_eq!(six.classify("KKKKQQ").name(), "Four of a kind and one pair");
+        assert!(six.classify("KKKKQQ") > six.classify("KKKQQQ"));
+        assert!(six.classify("KKKQQQ") > six.classify("KKKQQ2"));
+        assert!(six.classify("KKKQQ2") > six.classify("KKKQ32"));
+    }
+
+    #[test]
+    fn test_report() {
+        let jokers = Ruleset::jokers();
+        assert_eq!(jokers.substitute("T55J5"), Some('5'));
+        assert_eq!(jokers.substitute("KTJJT"), Some('T'));
+        assert_eq!(jokers.substitute("KTJQA"), Some('A'));
+        assert_eq!(jokers.substitute("JJJJJ"), Some('A'));
+        assert_eq!(jokers.substitute("32T3K"), None);
+        assert_eq!(Ruleset::standard().substitute("T55J5"), None);
+
+        let rows = report_rows(&jokers);
+        assert_eq!(rows.iter().map(|r| r.winnings).sum::<u32>(), part2());
+        assert_eq!(rows.last().unwrap().hand_type, "Five of a kind");
+    }
+}
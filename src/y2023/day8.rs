@@ -0,0 +1,525 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufRead};
+
+// Common
+
+/// A set of node ids, one bit per node.
+#[derive(Debug, Clone, PartialEq)]
+struct NodeSet {
+    words: Vec<u64>,
+}
+
+impl NodeSet {
+    fn new(len: usize) -> NodeSet {
+        NodeSet {
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    fn insert(&mut self, id: u32) {
+        self.words[id as usize / 64] |= 1 << (id % 64);
+    }
+
+    fn contains(&self, id: u32) -> bool {
+        self.words[id as usize / 64] & (1 << (id % 64)) != 0
+    }
+
+    fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        (0..self.words.len() as u32 * 64).filter(|id| self.contains(*id))
+    }
+}
+
+fn node_set(names: &[String], predicate: impl Fn(&str) -> bool) -> NodeSet {
+    let mut set = NodeSet::new(names.len());
+    for (id, name) in names.iter().enumerate() {
+        if predicate(name) {
+            set.insert(id as u32);
+        }
+    }
+    set
+}
+
+/// The desert map with node names interned to dense ids, in order of
+/// definition, so walking it only touches integers.
+#[derive(Debug)]
+struct Network {
+    names: Vec<String>,
+    ids: HashMap<String, u32>,
+    // (left, right) for each node id
+    links: Vec<(u32, u32)>,
+    // Nodes ending in A and Z, where ghosts start and stop
+    ghost_starts: NodeSet,
+    ghost_ends: NodeSet,
+}
+
+impl Network {
+    fn id(&self, name: &str) -> u32 {
+        *self
+            .ids
+            .get(name)
+            .unwrap_or_else(|| panic!("Unknown node {name}"))
+    }
+
+    fn select(&self, predicate: impl Fn(&str) -> bool) -> NodeSet {
+        node_set(&self.names, predicate)
+    }
+
+    fn all(&self) -> NodeSet {
+        self.select(|_| true)
+    }
+
+    fn reachable(&self, start: u32) -> NodeSet {
+        let mut seen = NodeSet::new(self.names.len());
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            if seen.contains(node) {
+                continue;
+            }
+            seen.insert(node);
+            let (left, right) = self.links[node as usize];
+            stack.extend([left, right]);
+        }
+        seen
+    }
+
+    /// Graphviz DOT for the given nodes and the edges between them, with ghost
+    /// starts and ends filled in.
+    fn to_dot(&self, nodes: &NodeSet) -> String {
+        let mut dot = String::from("digraph desert {\n");
+        for id in nodes.iter() {
+            let style = if self.ghost_starts.contains(id) {
+                " [style=filled, fillcolor=palegreen]"
+            } else if self.ghost_ends.contains(id) {
+                " [style=filled, fillcolor=salmon]"
+            } else {
+                ""
+            };
+            dot.push_str(&format!("  \"{}\"{style};\n", self.names[id as usize]));
+        }
+        for id in nodes.iter() {
+            let (left, right) = self.links[id as usize];
+            let edges = if left == right {
+                vec![(left, "L,R")]
+            } else {
+                vec![(left, "L"), (right, "R")]
+            };
+            for (to, label) in edges.into_iter().filter(|(to, _)| nodes.contains(*to)) {
+                dot.push_str(&format!(
+                    "  \"{}\" -> \"{}\" [label=\"{label}\"];\n",
+                    self.names[id as usize], self.names[to as usize]
+                ));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    fn next(&self, node: u32, instruction: char) -> u32 {
+        let (left, right) = self.links[node as usize];
+        if instruction == 'L' {
+            left
+        } else {
+            right
+        }
+    }
+}
+
+fn file_reader() -> io::BufReader<File> {
+    let file = File::open("./inputs/2023/day8.txt").expect("File not found");
+    return io::BufReader::new(file);
+}
+
+/// Parses `AAA = (BBB, CCC)`, allowing names of any length and any spacing.
+fn parse_node(line: &str) -> (&str, &str, &str) {
+    let (name, links) = line
+        .split_once('=')
+        .unwrap_or_else(|| panic!("Invalid node: {line}"));
+    let links = links.trim().trim_start_matches('(').trim_end_matches(')');
+    let (left, right) = links
+        .split_once(',')
+        .unwrap_or_else(|| panic!("Invalid node: {line}"));
+    (name.trim(), left.trim(), right.trim())
+}
+
+fn parse_input() -> (String, Network) {
+    parse_document(file_reader())
+}
+
+fn parse_document(reader: impl BufRead) -> (String, Network) {
+    let mut lines = reader
+        .lines()
+        .map(|l| l.expect("lines failed"))
+        .filter(|l| !l.trim().is_empty());
+    let instructions = lines.next().expect("No data found").trim().to_string();
+    let node_lines: Vec<String> = lines.collect();
+    let nodes: Vec<(&str, &str, &str)> = node_lines.iter().map(|l| parse_node(l)).collect();
+
+    let names: Vec<String> = nodes.iter().map(|(name, _, _)| name.to_string()).collect();
+    let ids: HashMap<String, u32> = names
+        .iter()
+        .enumerate()
+        .map(|(id, name)| (name.to_string(), id as u32))
+        .collect();
+    let id = |name: &str| {
+        *ids.get(name)
+            .unwrap_or_else(|| panic!("Undefined node {name}"))
+    };
+    let links = nodes
+        .iter()
+        .map(|(_, left, right)| (id(left), id(right)))
+        .collect();
+
+    let network = Network {
+        ghost_starts: node_set(&names, |n| n.ends_with('A')),
+        ghost_ends: node_set(&names, |n| n.ends_with('Z')),
+        names,
+        ids,
+        links,
+    };
+    (instructions, network)
+}
+
+fn steps_for_input(
+    starting_node: u32,
+    ending_nodes: &NodeSet,
+    instructions: &[char],
+    network: &Network,
+) -> u64 {
+    let mut steps: usize = 0;
+    let mut current_node = starting_node;
+
+    while !ending_nodes.contains(current_node) {
+        current_node = network.next(current_node, instructions[steps % instructions.len()]);
+        steps += 1;
+    }
+
+    steps as u64
+}
+
+// Part 1
+
+fn part1() -> u64 {
+    let (instructions, network) = parse_input();
+    let instructions: Vec<char> = instructions.chars().collect();
+    let ending_nodes = network.select(|n| n == "ZZZ");
+
+    steps_for_input(network.id("AAA"), &ending_nodes, &instructions, &network)
+}
+
+// Part 2
+
+fn least_common_factor(nums: Vec<u64>) -> u64 {
+    if nums.len() > 1 {
+        let mut num1 = nums[0];
+        let mut num2 = nums[1];
+
+        while num1 != num2 {
+            if num1 < num2 {
+                num1 += nums[0];
+            } else {
+                num2 += nums[1];
+            }
+        }
+
+        return least_common_factor([&[num1], &nums[2..]].concat());
+    }
+
+    return nums[0];
+}
+
+/// Where one ghost stands at an end node. The walk is deterministic in
+/// (node, instruction index), so after `cycle_start` steps it repeats every
+/// `cycle_len` steps.
+#[derive(Debug, PartialEq)]
+struct GhostCycle {
+    // End hits before the cycle starts, each seen once
+    transient_hits: Vec<u64>,
+    cycle_start: u64,
+    cycle_len: u64,
+    // End hits in the first pass around the cycle, repeating every cycle_len
+    cycle_hits: Vec<u64>,
+}
+
+impl GhostCycle {
+    fn arrives_at(&self, step: u64) -> bool {
+        if step < self.cycle_start {
+            return self.transient_hits.contains(&step);
+        }
+        let offset = (step - self.cycle_start) % self.cycle_len;
+        self.cycle_hits.contains(&(self.cycle_start + offset))
+    }
+
+    fn first_hit(&self) -> Option<u64> {
+        self.transient_hits
+            .first()
+            .or(self.cycle_hits.first())
+            .copied()
+    }
+}
+
+fn ghost_cycle(
+    starting_node: u32,
+    ending_nodes: &NodeSet,
+    instructions: &[char],
+    network: &Network,
+) -> GhostCycle {
+    // The step each (node, instruction index) state was first seen at
+    let mut seen = vec![u64::MAX; network.names.len() * instructions.len()];
+    let mut hits = Vec::new();
+    let mut current_node = starting_node;
+    let mut steps: u64 = 0;
+
+    loop {
+        let index = steps as usize % instructions.len();
+        let state = current_node as usize * instructions.len() + index;
+        if seen[state] != u64::MAX {
+            let cycle_start = seen[state];
+            let (transient_hits, cycle_hits) = hits.iter().partition(|h| **h < cycle_start);
+            return GhostCycle {
+                transient_hits,
+                cycle_start,
+                cycle_len: steps - cycle_start,
+                cycle_hits,
+            };
+        }
+        seen[state] = steps;
+
+        if ending_nodes.contains(current_node) {
+            hits.push(steps);
+        }
+
+        current_node = network.next(current_node, instructions[index]);
+        steps += 1;
+    }
+}
+
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - (a / b) * y)
+    }
+}
+
+/// Solves `x = a (mod m)` and `x = b (mod n)` for moduli that need not be
+/// coprime, as `x = residue (mod lcm)`.
+fn combine_congruences(a: i128, m: i128, b: i128, n: i128) -> Option<(i128, i128)> {
+    let (g, p, _) = extended_gcd(m, n);
+    if (b - a) % g != 0 {
+        return None;
+    }
+    let lcm = m / g * n;
+    let residue = a + m * ((b - a) / g * p % (n / g));
+    Some((residue.rem_euclid(lcm), lcm))
+}
+
+/// The first step at which every ghost stands on an end node at once.
+fn first_simultaneous_arrival(ghosts: &[GhostCycle]) -> Option<u64> {
+    let arrives_for_all = |step: u64| ghosts.iter().all(|g| g.arrives_at(step));
+
+    // Steps before some ghost's cycle starts only happen once.
+    let transient = ghosts
+        .iter()
+        .flat_map(|g| g.transient_hits.iter().copied())
+        .filter(|step| arrives_for_all(*step))
+        .min();
+
+    // Otherwise every ghost is on its cycle: (residue, modulus, earliest step)
+    let mut progressions: Vec<(i128, i128, u64)> = vec![(0, 1, 0)];
+    for ghost in ghosts {
+        let modulus = ghost.cycle_len as i128;
+        progressions = progressions
+            .iter()
+            .flat_map(|(residue, lcm, from)| {
+                ghost.cycle_hits.iter().filter_map(move |hit| {
+                    combine_congruences(*residue, *lcm, *hit as i128 % modulus, modulus)
+                        .map(|(r, l)| (r, l, (*from).max(*hit)))
+                })
+            })
+            .collect();
+    }
+    let periodic = progressions
+        .iter()
+        .map(|(residue, lcm, from)| {
+            let from = *from as i128;
+            (from + (residue - from).rem_euclid(*lcm)) as u64
+        })
+        .min();
+
+    match (transient, periodic) {
+        (Some(t), Some(p)) => Some(t.min(p)),
+        (t, p) => t.or(p),
+    }
+}
+
+fn ghost_cycles(instructions: &str, network: &Network) -> Vec<GhostCycle> {
+    let instructions: Vec<char> = instructions.chars().collect();
+
+    network
+        .ghost_starts
+        .iter()
+        .map(|n| ghost_cycle(n, &network.ghost_ends, &instructions, network))
+        .collect()
+}
+
+fn part2() -> u64 {
+    let (instructions, network) = parse_input();
+    first_simultaneous_arrival(&ghost_cycles(&instructions, &network)).expect("Ghosts never meet")
+}
+
+// Ghosts
+
+/// Describes each ghost's cycle and compares the exact answer with the least
+/// common multiple of the first arrivals, which assumes every ghost first
+/// reaches an end after exactly one period.
+pub fn ghosts(_args: &[String]) {
+    let (instructions, network) = parse_input();
+    let ghosts = ghost_cycles(&instructions, &network);
+
+    for (idx, ghost) in ghosts.iter().enumerate() {
+        println!(
+            "Ghost {}: cycle of {} steps from step {}, ends at {:?} then every cycle at {:?}",
+            idx + 1,
+            ghost.cycle_len,
+            ghost.cycle_start,
+            ghost.transient_hits,
+            ghost.cycle_hits
+        );
+    }
+
+    let exact = first_simultaneous_arrival(&ghosts);
+    match exact {
+        Some(step) => println!("First simultaneous arrival: {step}"),
+        None => println!("The ghosts never all arrive at once"),
+    }
+
+    let first_hits: Option<Vec<u64>> = ghosts.iter().map(|g| g.first_hit()).collect();
+    match first_hits {
+        Some(hits) if !hits.is_empty() => {
+            let lcm = least_common_factor(hits);
+            if exact == Some(lcm) {
+                println!("The LCM of first arrivals agrees");
+            } else {
+                println!("The LCM of first arrivals would have been wrong: {lcm}");
+            }
+        }
+        _ => println!("Some ghost never arrives, so there is no LCM answer"),
+    }
+}
+
+// Dot
+
+/// Prints the network as Graphviz DOT, optionally only what can be reached
+/// from the given start node.
+pub fn dot(args: &[String]) {
+    let (_, network) = parse_input();
+    let nodes = match args.first() {
+        Some(start) => network.reachable(network.id(start)),
+        None => network.all(),
+    };
+    print!("{}", network.to_dot(&nodes));
+}
+
+// Main
+
+pub fn run() {
+    println!("Part 1: {}", part1());
+    println!("Part 2: {}", part2());
+}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(), 20221);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(), 14616363770447);
+    }
+
+    #[test]
+    fn test_first_simultaneous_arrival() {
+        let document = "L
+
+11A = (11B, XXX)
+11B = (11Z, XXX)
+11Z = (11B, XXX)
+22A = (22Z, XXX)
+22Z = (22B, XXX)
+22B = (22C, XXX)
+22C = (22Z, XXX)
+33A = (33Z, XXX)
+33Z = (33B, XXX)
+33B = (33B, XXX)
+XXX = (XXX, XXX)
+";
+        let (instructions, network) = parse_document(document.as_bytes());
+        let cycles = ghost_cycles(&instructions, &network);
+        assert_eq!(
+            cycles[1],
+            GhostCycle {
+                transient_hits: vec![],
+                cycle_start: 1,
+                cycle_len: 3,
+                cycle_hits: vec![1],
+            }
+        );
+
+        // First arrivals at 2 and 1 have an LCM of 2, but only 4 works.
+        assert_eq!(first_simultaneous_arrival(&cycles[..2]), Some(4));
+        assert_eq!(least_common_factor(vec![2, 1]), 2);
+        // The third ghost only ever arrives once.
+        assert_eq!(first_simultaneous_arrival(&cycles[1..]), Some(1));
+        assert_eq!(first_simultaneous_arrival(&cycles), None);
+    }
+
+    #[test]
+    fn test_parse_document() {
+        let document = "RL\n\nSTART=( MIDDLE ,END)\nMIDDLE = (END, START)\n  END = (END,END)\n";
+        let (instructions, network) = parse_document(document.as_bytes());
+        assert_eq!(instructions, "RL");
+        assert_eq!(network.names, vec!["START", "MIDDLE", "END"]);
+        assert_eq!(network.links, vec![(1, 2), (2, 0), (2, 2)]);
+        assert_eq!(network.ghost_starts.iter().collect::<Vec<u32>>(), vec![]);
+        assert_eq!(
+            network
+                .select(|n| n.ends_with('D'))
+                .iter()
+                .collect::<Vec<u32>>(),
+            vec![2]
+        );
+
+        let chars: Vec<char> = instructions.chars().collect();
+        let end = network.select(|n| n == "END");
+        assert_eq!(
+            steps_for_input(network.id("START"), &end, &chars, &network),
+            1
+        );
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let document = "LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\n22A = (22B, XXX)\n22B = (22B, 22B)\nXXX = (XXX, XXX)\n";
+        let (_, network) = parse_document(document.as_bytes());
+
+        let dot = network.to_dot(&network.reachable(network.id("11A")));
+        assert!(
+            dot.starts_with("digraph desert {\n  \"11A\" [style=filled, fillcolor=palegreen];\n")
+        );
+        assert!(dot.contains("  \"11Z\" [style=filled, fillcolor=salmon];\n"));
+        assert!(dot.contains("  \"11B\" -> \"11Z\" [label=\"R\"];\n"));
+        assert!(dot.contains("  \"XXX\" -> \"XXX\" [label=\"L,R\"];\n"));
+        assert!(!dot.contains("22"));
+        assert_eq!(dot.matches("->").count(), 7);
+
+        assert_eq!(network.to_dot(&network.all()).matches("->").count(), 10);
+    }
+}
@@ -3,7 +3,7 @@ use std::fs;
 // Common
 
 fn parse_input() -> Vec<Vec<i32>> {
-    let contents = fs::read_to_string("./inputs/day9.txt").expect("No file found");
+    let contents = fs::read_to_string("./inputs/2023/day9.txt").expect("No file found");
     let lines = contents.split("\n");
 
     return lines
@@ -0,0 +1,74 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use crate::Mode;
+
+pub fn days() -> Vec<(u32, fn())> {
+    vec![
+        (1, day1::run),
+        (2, day2::run),
+        (3, day3::run),
+        (4, day4::run),
+        (5, day5::run),
+        (6, day6::run),
+        (7, day7::run),
+        (8, day8::run),
+        (9, day9::run),
+        (10, day10::run),
+        (11, day11::run),
+        (12, day12::run),
+        (13, day13::run),
+        (14, day14::run),
+        (15, day15::run),
+        (16, day16::run),
+        (17, day17::run),
+        (18, day18::run),
+        (19, day19::run),
+        (20, day20::run),
+        (22, day22::run),
+        (23, day23::run),
+        (24, day24::run),
+        (25, day25::run),
+    ]
+}
+
+pub fn modes() -> Vec<(u32, &'static str, Mode)> {
+    vec![
+        (1, "explain", day1::explain),
+        (2, "check", day2::check),
+        (2, "estimate", day2::estimate),
+        (3, "query", day3::query),
+        (4, "copies", day4::copies),
+        (4, "stream", day4::stream),
+        (5, "validate", day5::validate_almanac),
+        (5, "lookup", day5::lookup),
+        (6, "strategy", day6::strategy),
+        (7, "report", day7::report),
+        (8, "ghosts", day8::ghosts),
+        (8, "dot", day8::dot),
+        (22, "falling", day22::falling),
+        (23, "dot", day23::dot),
+    ]
+}